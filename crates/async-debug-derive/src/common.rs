@@ -1,44 +1,864 @@
 use convert_case::{Case, Casing};
-use proc_macro2::Ident;
-use syn::Attribute;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt, parse_quote, spanned::Spanned, Attribute, GenericArgument, GenericParam,
+    Generics, Lifetime, Type, Visibility, WhereClause, WherePredicate,
+};
 
-use self::{attr_struct_enum::AsyncDebugAttrStructEnum, prelude::*};
+use self::{attr_parse::FromAttributes, attr_struct_enum::AsyncDebugAttrStructEnum, prelude::*};
+use crate::fields::AsyncDebugField;
 
 pub trait AsyncDebugCommon {
-    fn get_async_debug_mod_ident(ident: &Ident) -> Ident {
-        Ident::new(
-            &format!("async_debug_{}", ident.to_string().to_case(Case::Snake)),
-            ident.span(),
+    /// Name of the module containing the generated struct/enum.
+    ///
+    /// `async_debug_{snake_case}` is only used when converting back to pascal case reproduces
+    /// `ident`, so the mapping is reversible. Any other ident is used verbatim after a double
+    /// underscore, which a snake cased pascal case ident can never start with, so no two idents
+    /// map to the same module.
+    fn get_async_debug_mod_ident(ident: &Ident, attr: &AsyncDebugAttrStructEnum) -> Ident {
+        if let Some(module) = &attr.module {
+            return module.clone();
+        }
+
+        let ident_str = ident.unraw().to_string();
+        let snake = ident_str.to_case(Case::Snake);
+
+        if snake.to_case(Case::Pascal) == ident_str {
+            format_ident!("async_debug_{}", snake, span = ident.span())
+        } else {
+            format_ident!("async_debug__{}", ident_str, span = ident.span())
+        }
+    }
+
+    fn get_attr_struct_enum(attrs: &[Attribute], serde: bool) -> Result<AsyncDebugAttrStructEnum> {
+        let mut attr = AsyncDebugAttrStructEnum::try_from_attributes(attrs)?.unwrap_or_default();
+        attr.serde = serde;
+
+        attr.validate()?;
+
+        Ok(attr)
+    }
+
+    /// Lifetime of the borrows held by the generated struct/enum, distinct from those in `generics`
+    fn get_snapshot_lifetime(generics: &Generics) -> Lifetime {
+        let mut name = "'async_debug".to_string();
+
+        while generics
+            .lifetimes()
+            .any(|param| param.lifetime.to_string() == name)
+        {
+            name.push('_');
+        }
+
+        Lifetime::new(&name, Span::call_site())
+    }
+
+    /// Name of every profile any of `fields` has a `profile(name, ...)` attribute for, in order
+    fn get_profiles<'f>(fields: impl Iterator<Item = &'f AsyncDebugField>) -> Vec<&'f Ident> {
+        let mut profiles: Vec<&Ident> = Vec::new();
+
+        for (name, _) in fields.flat_map(|field| &field.attr.profile) {
+            if !profiles.contains(&name) {
+                profiles.push(name);
+            }
+        }
+
+        profiles
+    }
+
+    /// `None` without the `non_exhaustive` argument, otherwise whether a struct or variant with
+    /// `attrs` is itself declared `#[non_exhaustive]`
+    fn get_non_exhaustive(attrs: &[Attribute], attr: &AsyncDebugAttrStructEnum) -> Option<bool> {
+        attr.non_exhaustive.map(|()| {
+            attrs
+                .iter()
+                .any(|attr| attr.path.is_ident("non_exhaustive"))
+        })
+    }
+
+    fn get_type_params(generics: &Generics) -> Vec<&Ident> {
+        generics.type_params().map(|param| &param.ident).collect()
+    }
+
+    /// Where clause of the generated impls, that of the struct/enum plus the inferred
+    /// `predicates`, or instead those of `bound = "..."` when given
+    fn get_where_clause(
+        generics: &Generics,
+        attr: &AsyncDebugAttrStructEnum,
+        predicates: Vec<WherePredicate>,
+    ) -> Option<WhereClause> {
+        Self::extend_where_clause(
+            generics.where_clause.clone(),
+            attr.bound.clone().unwrap_or(predicates),
         )
     }
 
-    fn get_attr_struct_enum(attrs: &[Attribute]) -> Result<AsyncDebugAttrStructEnum> {
-        Ok(AsyncDebugAttrStructEnum::try_from_attributes(attrs)?.unwrap_or_default())
+    /// Inferred `predicates` for rendering within a traversal, none when `bound = "..."` replaces
+    /// them in the where clause
+    fn get_rendered_predicates(
+        attr: &AsyncDebugAttrStructEnum,
+        predicates: impl IntoIterator<Item = WherePredicate>,
+    ) -> Vec<WherePredicate> {
+        if attr.bound.is_some() {
+            return Vec::new();
+        }
+
+        predicates.into_iter().collect()
+    }
+
+    fn extend_where_clause(
+        mut where_clause: Option<WhereClause>,
+        predicates: impl IntoIterator<Item = WherePredicate>,
+    ) -> Option<WhereClause> {
+        for predicate in predicates {
+            where_clause
+                .get_or_insert_with(|| parse_quote! { where })
+                .predicates
+                .push(predicate);
+        }
+
+        where_clause
+    }
+
+    /// Inherent `async_debug` method returning `output`
+    fn to_token_stream_fn_async_debug(
+        attr: &AsyncDebugAttrStructEnum,
+        vis: &Visibility,
+        field_types: Vec<&Type>,
+        context: bool,
+        output: TokenStream,
+        body: TokenStream,
+    ) -> TokenStream {
+        Self::to_token_stream_fn(
+            attr,
+            vis,
+            quote! { async_debug },
+            None,
+            None,
+            None,
+            field_types,
+            output,
+            Self::to_token_stream_snapshot_body(attr, context, body),
+        )
+    }
+
+    /// Inherent `try_async_debug` method returning `output`, or the first error of a `try` field
+    /// converted into the `error = ...` type, only with that argument. `body` returns early with
+    /// that error.
+    fn to_token_stream_fn_try_async_debug(
+        attr: &AsyncDebugAttrStructEnum,
+        vis: &Visibility,
+        field_types: Vec<&Type>,
+        context: bool,
+        output: TokenStream,
+        body: TokenStream,
+    ) -> Option<TokenStream> {
+        let error = attr.error.as_ref()?;
+
+        Some(Self::to_token_stream_fn(
+            attr,
+            vis,
+            quote! { try_async_debug },
+            None,
+            None,
+            None,
+            field_types,
+            quote! { ::core::result::Result<#output, #error> },
+            Self::to_token_stream_snapshot_body(
+                attr,
+                context,
+                quote! { ::core::result::Result::Ok(#body) },
+            ),
+        ))
+    }
+
+    /// `body` of `async_debug` or `try_async_debug`, within the `AsyncDebugContext` of the
+    /// struct/enum if any field is rendered within it
+    fn to_token_stream_snapshot_body(
+        attr: &AsyncDebugAttrStructEnum,
+        context: bool,
+        body: TokenStream,
+    ) -> TokenStream {
+        if !context {
+            return body;
+        }
+
+        let krate = attr.crate_path();
+        let context = AsyncDebugField::context_ident();
+        let limits = attr.to_token_stream_limits();
+
+        quote! {
+            let #context = #krate::AsyncDebugContext::new(self) #limits;
+            #body
+        }
+    }
+
+    /// Inherent `async_debug_with` method, rendering `self` through its `AsyncDebugNested`
+    /// implementation, as the root of a traversal with the given `AsyncDebugOptions`
+    fn to_token_stream_fn_async_debug_with(
+        attr: &AsyncDebugAttrStructEnum,
+        vis: &Visibility,
+        generics: &Generics,
+        where_clause: Option<WhereClause>,
+        field_types: Vec<&Type>,
+    ) -> Option<TokenStream> {
+        if attr.disable_derive_debug.is_some() {
+            return None;
+        }
+
+        let krate = attr.crate_path();
+        let lifetime = Self::get_snapshot_lifetime(generics);
+        let context = AsyncDebugField::context_ident();
+        let options = Ident::new("options", Span::mixed_site());
+        let limits = attr.to_token_stream_limits();
+
+        Some(Self::to_token_stream_fn(
+            attr,
+            vis,
+            quote! { async_debug_with },
+            Some(&lifetime),
+            Some(quote! { #options: &#lifetime #krate::AsyncDebugOptions }),
+            where_clause,
+            field_types,
+            quote! { #krate::Rendered },
+            quote! {
+                let #context = <#krate::AsyncDebugContext as ::core::default::Default>::default()
+                    #limits
+                    .with_options(#options);
+                #krate::AsyncDebugNested::async_debug_nested(self, &#context).await
+            },
+        ))
+    }
+
+    /// Inherent `async_debug_{profile}` method for each of the `profiles` of any field, rendering
+    /// `self` with `async_debug_with()`
+    fn to_token_stream_fn_async_debug_profiles(
+        attr: &AsyncDebugAttrStructEnum,
+        vis: &Visibility,
+        where_clause: Option<WhereClause>,
+        profiles: Vec<&Ident>,
+    ) -> TokenStream {
+        if attr.disable_derive_debug.is_some() {
+            return quote! {};
+        }
+
+        let krate = attr.crate_path();
+        let options = Ident::new("options", Span::mixed_site());
+
+        profiles
+            .into_iter()
+            .map(|profile| {
+                let profile = profile.unraw();
+                let name = profile.to_string();
+
+                Self::to_token_stream_fn(
+                    attr,
+                    vis,
+                    format_ident!("async_debug_{}", profile).to_token_stream(),
+                    None,
+                    None,
+                    where_clause.clone(),
+                    Vec::new(),
+                    quote! { #krate::Rendered },
+                    quote! {
+                        let #options = #krate::AsyncDebugOptions::new().with_profile(#name);
+                        self.async_debug_with(&#options).await
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Inherent async method `ident`, borrowing `self` and any references in `args` for `lifetime`,
+    /// which may only be elided without `args`.
+    ///
+    /// With `send` the returned future is declared `Send`, as it borrows `self` every field type is
+    /// first asserted `Sync` so the error points at the offending field rather than the method.
+    /// The body is bound with its type so references to enum bindings still coerce.
+    #[allow(clippy::too_many_arguments)]
+    fn to_token_stream_fn(
+        attr: &AsyncDebugAttrStructEnum,
+        vis: &Visibility,
+        ident: TokenStream,
+        lifetime: Option<&Lifetime>,
+        args: Option<TokenStream>,
+        where_clause: Option<WhereClause>,
+        field_types: Vec<&Type>,
+        output: TokenStream,
+        body: TokenStream,
+    ) -> TokenStream {
+        let (generics, receiver, lifetime) = match lifetime {
+            Some(lifetime) => (
+                quote! { <#lifetime> },
+                quote! { &#lifetime self },
+                quote! { #lifetime },
+            ),
+            None => (quote! {}, quote! { &self }, quote! { '_ }),
+        };
+        let args = args.map(|args| quote! { , #args });
+
+        if attr.send.is_none() {
+            return quote! {
+                #vis async fn #ident #generics (#receiver #args) -> #output
+                #where_clause
+                {
+                    #body
+                }
+            };
+        }
+
+        let krate = attr.crate_path();
+        let assertions = field_types.into_iter().map(|ty| {
+            quote_spanned! {ty.span()=>
+                #krate::assert_field_is_sync::<#ty>();
+            }
+        });
+
+        let snapshot = Ident::new("snapshot", Span::mixed_site());
+
+        quote! {
+            #vis fn #ident #generics (#receiver #args)
+                -> impl ::core::future::Future<Output = #output> + ::core::marker::Send + #lifetime
+            #where_clause
+            {
+                #(#assertions)*
+
+                async move {
+                    let #snapshot: #output = { #body };
+                    #snapshot
+                }
+            }
+        }
+    }
+
+    /// `impl AsyncDebugNested`, evaluating `body`, which renders every field, within the traversal
+    /// of an enclosing struct/enum, unless `self` is already being rendered further up.
+    ///
+    /// A `recursive` struct/enum would contain its own future, so the future is boxed. The boxed
+    /// future is named in the signature, an opaque type would need its own `Send`ness to be
+    /// inferred while inferring it.
+    #[allow(clippy::too_many_arguments)]
+    fn to_token_stream_impl_async_debug_nested(
+        attr: &AsyncDebugAttrStructEnum,
+        ident: &Ident,
+        generics: &Generics,
+        where_clause: &Option<WhereClause>,
+        recursive: bool,
+        body: TokenStream,
+    ) -> Option<TokenStream> {
+        if attr.disable_derive_debug.is_some() {
+            return None;
+        }
+
+        let krate = attr.crate_path();
+        let lifetime = Self::get_snapshot_lifetime(generics);
+        let (generics_impl, generics_ty, _) = generics.split_for_impl();
+        let context = AsyncDebugField::context_ident();
+        let cycle = Ident::new("cycle", Span::mixed_site());
+
+        let future = quote! {
+            async move {
+                let #context = match #context.enter(self) {
+                    ::core::result::Result::Ok(#context) => #context,
+                    ::core::result::Result::Err(#cycle) => return #cycle,
+                };
+                #body
+            }
+        };
+
+        let ts_fn = if recursive {
+            let box_future = if attr.local.is_some() {
+                quote! { #krate::LocalBoxFuture }
+            } else {
+                quote! { #krate::BoxFuture }
+            };
+
+            quote! {
+                #[allow(refining_impl_trait)]
+                fn async_debug_nested<#lifetime>(
+                    &#lifetime self,
+                    #context: &#lifetime #krate::AsyncDebugContext,
+                ) -> #box_future<#lifetime, #krate::Rendered> {
+                    ::std::boxed::Box::pin(#future)
+                }
+            }
+        } else {
+            quote! {
+                fn async_debug_nested<#lifetime>(
+                    &#lifetime self,
+                    #context: &#lifetime #krate::AsyncDebugContext,
+                ) -> impl ::core::future::Future<Output = #krate::Rendered> + #lifetime {
+                    #future
+                }
+            }
+        };
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #generics_impl #krate::AsyncDebugNested for #ident #generics_ty #where_clause {
+                #ts_fn
+            }
+        })
+    }
+
+    /// `impl AsyncDebugFlatten`, naming the generated struct `snapshot` as returned by
+    /// `async_debug`, and evaluating `body`, which renders every field, within the traversal of
+    /// the struct `self` is flattened into, whose fields are named by `field_names`
+    fn to_token_stream_impl_async_debug_flatten(
+        attr: &AsyncDebugAttrStructEnum,
+        ident: &Ident,
+        generics: &Generics,
+        where_clause: &Option<WhereClause>,
+        snapshot: TokenStream,
+        field_names: TokenStream,
+        body: TokenStream,
+    ) -> Option<TokenStream> {
+        if attr.disable_derive_debug.is_some() || attr.transparent.is_some() {
+            return None;
+        }
+
+        let krate = attr.crate_path();
+        let lifetime = Self::get_snapshot_lifetime(generics);
+        let (generics_impl, generics_ty, _) = generics.split_for_impl();
+        let context = AsyncDebugField::context_ident();
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #generics_impl #krate::AsyncDebugFlatten for #ident #generics_ty #where_clause {
+                type Snapshot<#lifetime> where Self: #lifetime = #snapshot;
+
+                const FIELD_NAMES: #krate::FieldNames<'static> = #field_names;
+
+                fn async_debug_flatten_snapshot(
+                    &self,
+                ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+                    self.async_debug()
+                }
+
+                fn async_debug_flatten<#lifetime>(
+                    &#lifetime self,
+                    #context: &#lifetime #krate::AsyncDebugContext,
+                ) -> impl ::core::future::Future<Output = #krate::RenderedFields> + #lifetime {
+                    async move { #body }
+                }
+            }
+        })
+    }
+
+    /// `impl Debug` for the generated struct/enum, instead of deriving it, so fields skipped by
+    /// `skip_if` can be left out, `non_exhaustive` ones end in `..`, `flatten`ed ones are written
+    /// in place and `transparent` ones render as their only field. `body` writes `self` to the
+    /// formatter, see `to_token_stream_debug_body`.
+    fn to_token_stream_impl_debug(
+        attr: &AsyncDebugAttrStructEnum,
+        ident: &Ident,
+        generics_names: &[GenericArgument],
+        where_clause: &Option<WhereClause>,
+        body: TokenStream,
+    ) -> Option<TokenStream> {
+        if attr.disable_derive_debug.is_some() {
+            return None;
+        }
+
+        let formatter = Ident::new("f", Span::mixed_site());
+
+        Some(quote! {
+            #[allow(non_camel_case_types)]
+            #[automatically_derived]
+            impl <#(#generics_names: ::core::fmt::Debug),*> ::core::fmt::Debug
+                for #ident <#(#generics_names),*>
+            #where_clause
+            {
+                fn fmt(&self, #formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #body
+                }
+            }
+        })
+    }
+
+    /// `impl DebugFields` for the generated struct, so its fields can be written in place of a
+    /// field of the struct it is flattened into. `body` writes them to `debug`, see
+    /// `to_token_stream_debug_fields`.
+    fn to_token_stream_impl_debug_fields(
+        attr: &AsyncDebugAttrStructEnum,
+        ident: &Ident,
+        generics_names: &[GenericArgument],
+        where_clause: &Option<WhereClause>,
+        body: TokenStream,
+    ) -> TokenStream {
+        let krate = attr.crate_path();
+        let debug = AsyncDebugField::debug_ident();
+
+        quote! {
+            #[allow(non_camel_case_types)]
+            #[automatically_derived]
+            impl <#(#generics_names: ::core::fmt::Debug),*> #krate::DebugFields
+                for #ident <#(#generics_names),*>
+            #where_clause
+            {
+                fn debug_fields(&self, #debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Writes the struct, or tuple, `name` to the formatter of `to_token_stream_impl_debug`, with
+    /// `fields` writing each field to `debug`, followed by `..` if `non_exhaustive`
+    fn to_token_stream_debug_body(
+        name: &str,
+        tuple: bool,
+        non_exhaustive: bool,
+        fields: TokenStream,
+    ) -> TokenStream {
+        let formatter = Ident::new("f", Span::mixed_site());
+        let debug = AsyncDebugField::debug_ident();
+
+        let debug_fn = if tuple {
+            quote! { debug_tuple }
+        } else {
+            quote! { debug_struct }
+        };
+        let finish = if non_exhaustive {
+            quote! { finish_non_exhaustive }
+        } else {
+            quote! { finish }
+        };
+
+        quote! {
+            {
+                let mut #debug = #formatter.#debug_fn(#name);
+                #fields
+                #debug.#finish()
+            }
+        }
+    }
+
+    /// Writes `value`, the only field of a `transparent` struct, to the formatter of
+    /// `to_token_stream_impl_debug` as is
+    fn to_token_stream_debug_transparent(value: TokenStream) -> TokenStream {
+        let formatter = Ident::new("f", Span::mixed_site());
+
+        quote! {
+            ::core::fmt::Debug::fmt(&#value, #formatter)
+        }
+    }
+
+    /// `impl AsyncDebug`, naming the generated struct/enum as the associated `Snapshot` type,
+    /// and the optional `snapshot = ...` type alias for it
+    fn to_token_stream_impl_async_debug(
+        attr: &AsyncDebugAttrStructEnum,
+        vis: &Visibility,
+        ident: &Ident,
+        generics: &Generics,
+        where_clause: &Option<WhereClause>,
+        snapshot_ty: TokenStream,
+    ) -> TokenStream {
+        let krate = attr.crate_path();
+        let lifetime = Self::get_snapshot_lifetime(generics);
+        let (generics_impl, generics_ty, _) = generics.split_for_impl();
+
+        let ts_impl = quote! {
+            impl #generics_impl #krate::AsyncDebug for #ident #generics_ty #where_clause {
+                type Snapshot<#lifetime> where Self: #lifetime = #snapshot_ty;
+            }
+        };
+
+        let ts_alias = attr.snapshot.as_ref().map(|snapshot| {
+            let vis = attr.snapshot_vis.as_ref().unwrap_or(vis);
+            let params = generics.params.iter().map(|param| match param {
+                GenericParam::Type(param) => {
+                    let ident = &param.ident;
+                    let default = param.default.as_ref().map(|ty| quote! { = #ty });
+                    quote! { #ident #default }
+                }
+                GenericParam::Lifetime(param) => {
+                    let lifetime = &param.lifetime;
+                    quote! { #lifetime }
+                }
+                GenericParam::Const(param) => {
+                    let ident = &param.ident;
+                    let ty = &param.ty;
+                    let default = param.default.as_ref().map(|expr| quote! { = #expr });
+                    quote! { const #ident: #ty #default }
+                }
+            });
+
+            quote! {
+                #vis type #snapshot<#lifetime, #(#params),*> =
+                    <#ident #generics_ty as #krate::AsyncDebug>::Snapshot<#lifetime>;
+            }
+        });
+
+        quote! {
+            #ts_impl
+            #ts_alias
+        }
     }
 }
 
-pub mod attr_prop {
-    use bae::FromAttributes;
-    use syn::{spanned::Spanned, Expr, Type};
+pub mod attr_parse {
+    use proc_macro2::{Ident, TokenStream};
+    use quote::quote;
+    use syn::{
+        ext::IdentExt,
+        parenthesized,
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+        Attribute, LitInt, Token,
+    };
 
     use crate::common::prelude::*;
 
-    #[derive(FromAttributes, Default)]
+    const ATTR_NAME: &str = "async_debug";
+
+    /// Parses and merges every `#[async_debug(...)]` attribute in a list of attributes.
+    ///
+    /// Arguments are either switches (`skip`), values (`ty = String`) or lists (`derive(Clone)`),
+    /// implementors dispatch on the argument name in `parse_arg`.
+    pub trait FromAttributes: Default {
+        const ARGS: &'static [&'static str];
+
+        /// Parse the remainder of the argument named `arg`, returning `false` if it is unknown
+        fn parse_arg(&mut self, arg: &Ident, input: ParseStream) -> Result<bool>;
+
+        fn try_from_attributes(attrs: &[Attribute]) -> Result<Option<Self>> {
+            let mut res = None;
+
+            for attr in attrs.iter().filter(|attr| attr.path.is_ident(ATTR_NAME)) {
+                let this = res.get_or_insert_with(Self::default);
+
+                attr.parse_args_with(|input: ParseStream| this.parse_args(input))?;
+            }
+
+            Ok(res)
+        }
+
+        /// Every argument listed when an unknown one is found, `ARGS` unless `parse_arg` also
+        /// accepts those of another implementor
+        fn supported_args() -> Vec<&'static str> {
+            Self::ARGS.to_vec()
+        }
+
+        fn parse_args(&mut self, input: ParseStream) -> Result<()> {
+            while !input.is_empty() {
+                let arg = Ident::parse_any(input)?;
+
+                if !self.parse_arg(&arg, input)? {
+                    let mut supported = Self::supported_args()
+                        .iter()
+                        .map(|arg| format!("`{}`", arg))
+                        .collect::<Vec<_>>();
+                    supported.sort_unstable();
+
+                    return Err(Error::new(
+                        arg.span(),
+                        format!(
+                            "`#[{}]` got unknown `{}` argument. Supported arguments are {}",
+                            ATTR_NAME,
+                            arg,
+                            supported.join(", "),
+                        ),
+                    ));
+                }
+
+                if input.is_empty() {
+                    break;
+                }
+
+                input.parse::<Token![,]>()?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// `arg = value`
+    pub fn parse_value<T: Parse>(input: ParseStream) -> Result<T> {
+        input.parse::<Token![=]>()?;
+        input.parse()
+    }
+
+    /// `arg(value, value, ...)`
+    pub fn parse_list<T: Parse>(input: ParseStream) -> Result<Vec<T>> {
+        let content;
+        parenthesized!(content in input);
+
+        Ok(Punctuated::<T, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect())
+    }
+
+    /// `arg = 42`
+    pub fn parse_usize(input: ParseStream) -> Result<usize> {
+        parse_value::<LitInt>(input)?.base10_parse()
+    }
+
+    /// `.with_max_depth(..)`, `.with_max_items(..)` and `.with_max_len(..)` calls on an
+    /// `AsyncDebugContext`, for each limit that is set
+    pub fn to_token_stream_limits(
+        max_depth: Option<usize>,
+        max_items: Option<usize>,
+        max_len: Option<usize>,
+    ) -> TokenStream {
+        let max_depth = max_depth.map(|max_depth| quote! { .with_max_depth(#max_depth) });
+        let max_items = max_items.map(|max_items| quote! { .with_max_items(#max_items) });
+        let max_len = max_len.map(|max_len| quote! { .with_max_len(#max_len) });
+
+        quote! { #max_depth #max_items #max_len }
+    }
+
+    /// `arg(any tokens)`
+    pub fn parse_tokens(input: ParseStream) -> Result<TokenStream> {
+        let content;
+        parenthesized!(content in input);
+
+        content.parse()
+    }
+}
+
+pub mod attr_prop {
+    use proc_macro2::{Ident, TokenStream};
+    use syn::{
+        ext::IdentExt, parenthesized, parse::ParseStream, spanned::Spanned, Expr, Token, Type,
+    };
+
+    use crate::common::{
+        attr_parse::{
+            parse_tokens, parse_usize, parse_value, to_token_stream_limits, FromAttributes,
+        },
+        prelude::*,
+    };
+
+    #[derive(Clone, Default)]
     pub struct AsyncDebug {
         pub async_call: Option<Expr>,
+        pub map: Option<Expr>,
         pub clone: Option<()>,
         pub copy: Option<()>,
+        pub to_owned: Option<()>,
+        pub to_string: Option<()>,
+        pub into: Option<Type>,
         pub ty: Option<Type>,
+        pub nested: Option<()>,
+        pub flatten: Option<()>,
+        pub optional: Option<()>,
+        pub upgrade: Option<()>,
+        pub r#try: Option<()>,
+
+        pub truncate: Option<()>,
+        pub max_depth: Option<usize>,
+        pub max_items: Option<usize>,
+        pub max_len: Option<usize>,
 
         pub skip: Option<()>,
+        pub skip_if: Option<Expr>,
+        pub skip_if_empty: Option<()>,
+
+        pub attr: Vec<TokenStream>,
+
+        /// Name and arguments of each `profile(name, ...)`
+        pub profile: Vec<(Ident, AsyncDebug)>,
+    }
+
+    impl FromAttributes for AsyncDebug {
+        const ARGS: &'static [&'static str] = &[
+            "async_call",
+            "map",
+            "clone",
+            "copy",
+            "to_owned",
+            "to_string",
+            "into",
+            "ty",
+            "nested",
+            "flatten",
+            "optional",
+            "upgrade",
+            "try",
+            "truncate",
+            "max_depth",
+            "max_items",
+            "max_len",
+            "skip",
+            "skip_if",
+            "skip_if_empty",
+            "attr",
+            "profile",
+        ];
+
+        fn parse_arg(&mut self, arg: &Ident, input: ParseStream) -> Result<bool> {
+            match arg.to_string().as_str() {
+                "async_call" => self.async_call = Some(parse_value(input)?),
+                "map" => self.map = Some(parse_value(input)?),
+                "clone" => self.clone = Some(()),
+                "copy" => self.copy = Some(()),
+                "to_owned" => self.to_owned = Some(()),
+                "to_string" => self.to_string = Some(()),
+                "into" => self.into = Some(parse_value(input)?),
+                "ty" => self.ty = Some(parse_value(input)?),
+                "nested" => self.nested = Some(()),
+                "flatten" => self.flatten = Some(()),
+                "optional" => self.optional = Some(()),
+                "upgrade" => self.upgrade = Some(()),
+                "try" => self.r#try = Some(()),
+                "truncate" => self.truncate = Some(()),
+                "max_depth" => self.max_depth = Some(parse_usize(input)?),
+                "max_items" => self.max_items = Some(parse_usize(input)?),
+                "max_len" => self.max_len = Some(parse_usize(input)?),
+                "skip" => self.skip = Some(()),
+                "skip_if" => self.skip_if = Some(parse_value(input)?),
+                "skip_if_empty" => self.skip_if_empty = Some(()),
+                "attr" => self.attr.push(parse_tokens(input)?),
+                "profile" => self.parse_profile(input)?,
+                _ => return Ok(false),
+            }
+
+            Ok(true)
+        }
     }
 
     impl AsyncDebug {
+        /// `profile(name, arg, arg, ...)`, merged with any earlier `profile(name, ...)`
+        fn parse_profile(&mut self, input: ParseStream) -> Result<()> {
+            let content;
+            parenthesized!(content in input);
+
+            let name = Ident::parse_any(&content)?;
+
+            let index = match self.profile.iter().position(|(other, _)| *other == name) {
+                Some(index) => index,
+                None => {
+                    self.profile.push((name, Self::default()));
+                    self.profile.len() - 1
+                }
+            };
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+                self.profile[index].1.parse_args(&content)?;
+            }
+
+            Ok(())
+        }
+
         pub fn validate(&self, spanned: &impl Spanned) -> Result<()> {
             let mut res = Vec::new();
 
             if self.skip.is_some()
-                && (self.async_call.is_some() || self.clone.is_some() || self.copy.is_some())
+                && (self.async_call.is_some()
+                    || self.map.is_some()
+                    || self.has_conversion()
+                    || self.nested.is_some()
+                    || self.flatten.is_some()
+                    || self.optional.is_some()
+                    || self.upgrade.is_some()
+                    || self.r#try.is_some()
+                    || self.has_limits()
+                    || self.is_skippable()
+                    || !self.attr.is_empty()
+                    || !self.profile.is_empty())
             {
                 res.push(Err(Error::new(
                     spanned.span(),
@@ -46,35 +866,829 @@ pub mod attr_prop {
                 )));
             }
 
-            if self.clone.is_some() && self.copy.is_some() {
+            if self.flatten.is_some()
+                && (self.async_call.is_some()
+                    || self.map.is_some()
+                    || self.has_conversion()
+                    || self.ty.is_some()
+                    || self.nested.is_some()
+                    || self.optional.is_some()
+                    || self.upgrade.is_some()
+                    || self.r#try.is_some()
+                    || self.has_limits()
+                    || self.is_skippable())
+            {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "flatten can only be used with attr and profile",
+                )));
+            }
+
+            let conversions = [
+                self.clone.is_some(),
+                self.copy.is_some(),
+                self.to_owned.is_some(),
+                self.to_string.is_some(),
+                self.into.is_some(),
+            ];
+
+            if conversions.iter().filter(|conversion| **conversion).count() > 1 {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "clone, copy, to_owned, to_string and into are mutually exclusive",
+                )));
+            }
+
+            if self.ty.is_some() && (self.to_string.is_some() || self.into.is_some()) {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "ty can not be used with to_string or into, which determine the type",
+                )));
+            }
+
+            if self.to_owned.is_some()
+                && self.ty.is_none()
+                && (self.async_call.is_some() || self.map.is_some())
+            {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "to_owned requires ty after async_call or map, the type it returns can not be inferred",
+                )));
+            }
+
+            if self.nested.is_some() && (self.has_conversion() || self.ty.is_some()) {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "nested can not be used with clone, copy, to_owned, to_string, into or ty",
+                )));
+            }
+
+            if self.nested.is_some() && self.truncate.is_some() {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "truncate can not be used with nested, which is always truncated",
+                )));
+            }
+
+            if self.nested.is_none()
+                && self.has_limits()
+                && (self.has_conversion() || self.ty.is_some())
+            {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "truncate, max_items and max_len can not be used with clone, copy, to_owned, to_string, into or ty",
+                )));
+            }
+
+            if self.nested.is_none() && self.max_depth.is_some() {
                 res.push(Err(Error::new(
                     spanned.span(),
-                    "clone and copy are mutually exclusive",
+                    "max_depth can only be used with nested",
                 )));
             }
 
             if let Some(async_call) = &self.async_call {
-                if !matches!(async_call, Expr::Path(_)) {
+                if AsyncCallStep::parse(async_call).is_none() {
+                    let message = if let Expr::Array(_) = async_call {
+                        "async_call = [...] must list paths to functions, optionally ending with each(...) of those"
+                    } else {
+                        "async_call must be a path to a function"
+                    };
+
+                    res.push(Err(Error::new(spanned.span(), message)));
+                }
+            }
+
+            if let Some(map) = &self.map {
+                if !matches!(map, Expr::Path(_)) {
+                    res.push(Err(Error::new(
+                        spanned.span(),
+                        "map must be a path to a function",
+                    )));
+                }
+            }
+
+            if self.map.is_some() && !self.has_known_ty() && !self.renders_within_context() {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "map requires ty, to_string, into, nested or truncate, the type it returns can not be inferred",
+                )));
+            }
+
+            if self.each_depth() > 0 && self.is_skippable() {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "skip_if and skip_if_empty can not be used with each(...) in async_call",
+                )));
+            }
+
+            if self.each_depth() > 0 && !self.has_known_ty() && !self.renders_within_context() {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "each(...) in async_call requires ty, to_string, into, nested or truncate, the type of the items can not be inferred",
+                )));
+            }
+
+            if (self.optional.is_some() || self.upgrade.is_some() || self.r#try.is_some())
+                && self.is_skippable()
+            {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "skip_if and skip_if_empty can not be used with optional, upgrade or try",
+                )));
+            }
+
+            if self.r#try.is_some() && self.async_call.is_none() {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "try requires async_call, whose result it unwraps",
+                )));
+            }
+
+            if self.r#try.is_some()
+                && !self.has_conversion()
+                && self.ty.is_none()
+                && !self.renders_within_context()
+            {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "try requires clone, copy, to_owned, to_string, into, ty, nested or truncate, the value it unwraps can not be borrowed",
+                )));
+            }
+
+            if self.upgrade.is_some()
+                && !self.has_conversion()
+                && self.ty.is_none()
+                && !self.renders_within_context()
+            {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "upgrade requires clone, copy, to_owned, to_string, into, ty, nested or truncate, the upgraded value can not be borrowed",
+                )));
+            }
+
+            if self.skip_if.is_some() && self.skip_if_empty.is_some() {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "skip_if and skip_if_empty are mutually exclusive",
+                )));
+            }
+
+            if let Some(skip_if) = &self.skip_if {
+                if !matches!(skip_if, Expr::Path(_)) {
                     res.push(Err(Error::new(
                         spanned.span(),
-                        "async_call must be a path to a function",
+                        "skip_if must be a path to a function",
+                    )));
+                }
+            }
+
+            for (name, profile) in &self.profile {
+                if name == "with" {
+                    res.push(Err(Error::new(
+                        name.span(),
+                        "profile can not be named `with`, as `async_debug_with` is already generated",
+                    )));
+                }
+
+                if !profile.attr.is_empty()
+                    || profile.flatten.is_some()
+                    || profile.optional.is_some()
+                    || profile.upgrade.is_some()
+                    || !profile.profile.is_empty()
+                {
+                    res.push(Err(Error::new(
+                        name.span(),
+                        "profile can not contain attr, flatten, optional, upgrade or profile",
                     )));
+                } else if profile.skip.is_some() {
+                    res.push(profile.validate(spanned));
+                } else {
+                    res.push(self.merge(profile).validate(spanned));
                 }
             }
 
             res.into_iter().collect_syn_error()
         }
+
+        /// These arguments, with those set in `profile` replacing them.
+        ///
+        /// The conversion, e.g. `clone`, and `ty` are replaced together, and dropped when `profile`
+        /// renders the value through `nested` or `truncate`, they only shape the value of the
+        /// generated struct/enum.
+        pub fn merge(&self, profile: &Self) -> Self {
+            let convert = if profile.has_conversion() || profile.ty.is_some() {
+                profile
+            } else if profile.nested.is_some() || profile.has_limits() {
+                &Self::default()
+            } else {
+                self
+            };
+
+            Self {
+                async_call: profile
+                    .async_call
+                    .clone()
+                    .or_else(|| self.async_call.clone()),
+                map: profile.map.clone().or_else(|| self.map.clone()),
+                clone: convert.clone,
+                copy: convert.copy,
+                to_owned: convert.to_owned,
+                to_string: convert.to_string,
+                into: convert.into.clone(),
+                ty: convert.ty.clone(),
+                nested: profile.nested.or(self.nested),
+                flatten: self.flatten,
+                optional: self.optional,
+                upgrade: self.upgrade,
+                r#try: profile.r#try.or(self.r#try),
+                truncate: profile.truncate.or(self.truncate),
+                max_depth: profile.max_depth.or(self.max_depth),
+                max_items: profile.max_items.or(self.max_items),
+                max_len: profile.max_len.or(self.max_len),
+                skip: profile.skip.or(self.skip),
+                skip_if: profile.skip_if.clone().or_else(|| self.skip_if.clone()),
+                skip_if_empty: profile.skip_if_empty.or(self.skip_if_empty),
+                attr: self.attr.clone(),
+                profile: Vec::new(),
+            }
+        }
+
+        /// Steps of `async_call`, empty without it
+        pub fn async_call_steps(&self) -> Vec<AsyncCallStep<'_>> {
+            self.async_call
+                .as_ref()
+                .and_then(AsyncCallStep::parse)
+                .unwrap_or_default()
+        }
+
+        /// How many `each(...)` steps of `async_call` are nested in one another, the value of the
+        /// field is an `Each` of that many levels
+        pub fn each_depth(&self) -> usize {
+            let mut steps = self.async_call_steps();
+            let mut depth = 0;
+
+            while let Some(AsyncCallStep::Each(each)) = steps.pop() {
+                steps = each;
+                depth += 1;
+            }
+
+            depth
+        }
+
+        /// Whether any of `clone`, `copy`, `to_owned`, `to_string` or `into` is set
+        pub fn has_conversion(&self) -> bool {
+            self.clone.is_some()
+                || self.copy.is_some()
+                || self.to_owned.is_some()
+                || self.to_string.is_some()
+                || self.into.is_some()
+        }
+
+        /// Whether the type of the value is given by `ty`, or determined by `to_string` or `into`
+        pub fn has_known_ty(&self) -> bool {
+            self.ty.is_some() || self.to_string.is_some() || self.into.is_some()
+        }
+
+        /// Whether the field is rendered through `AsyncDebugNested` or `AsyncDebugTruncate`
+        pub fn renders_within_context(&self) -> bool {
+            self.nested.is_some() || self.has_limits()
+        }
+
+        /// Whether `skip_if` or `skip_if_empty` is set, so the field may be absent
+        pub fn is_skippable(&self) -> bool {
+            self.skip_if.is_some() || self.skip_if_empty.is_some()
+        }
+
+        /// Whether any of `truncate`, `max_depth`, `max_items` or `max_len` is set
+        pub fn has_limits(&self) -> bool {
+            self.truncate.is_some()
+                || self.max_depth.is_some()
+                || self.max_items.is_some()
+                || self.max_len.is_some()
+        }
+
+        /// `AsyncDebugContext` builder calls applying `max_depth`, `max_items` and `max_len`
+        pub fn to_token_stream_limits(&self) -> TokenStream {
+            to_token_stream_limits(self.max_depth, self.max_items, self.max_len)
+        }
+    }
+
+    /// A step of an `async_call` pipeline, e.g. `async_call = [Mutex::lock, each(RwLock::read)]`
+    pub enum AsyncCallStep<'a> {
+        /// Path to a function awaited on a reference to the value
+        Call(&'a Expr),
+        /// `each(...)`, the steps awaited on every item of the value, through `AsyncDebugEach`
+        Each(Vec<AsyncCallStep<'a>>),
+    }
+
+    impl<'a> AsyncCallStep<'a> {
+        /// Steps of `some_function`, `each(...)` or `[some_function, ..., each(...)]`, `None` if
+        /// `expr` is none of those
+        pub fn parse(expr: &'a Expr) -> Option<Vec<Self>> {
+            let Expr::Array(array) = expr else {
+                return Self::parse_step(expr).map(|step| vec![step]);
+            };
+
+            let steps = array
+                .elems
+                .iter()
+                .map(Self::parse_step)
+                .collect::<Option<Vec<_>>>()?;
+            let (_, init) = steps.split_last()?;
+
+            init.iter()
+                .all(|step| matches!(step, Self::Call(_)))
+                .then_some(steps)
+        }
+
+        fn parse_step(expr: &'a Expr) -> Option<Self> {
+            match expr {
+                Expr::Path(_) => Some(Self::Call(expr)),
+                Expr::Call(call)
+                    if call.args.len() == 1
+                        && matches!(&*call.func, Expr::Path(func) if func.path.is_ident("each")) =>
+                {
+                    Self::parse(&call.args[0]).map(Self::Each)
+                }
+                _ => None,
+            }
+        }
     }
 
     pub use AsyncDebug as AsyncDebugAttrField;
 }
 
+pub mod attr_variant {
+    use proc_macro2::{Ident, TokenStream};
+    use quote::quote;
+    use syn::{ext::IdentExt, parse::ParseStream, spanned::Spanned, LitStr};
+
+    use crate::common::{
+        attr_parse::{parse_value, FromAttributes},
+        prelude::*,
+    };
+
+    #[derive(Default)]
+    pub struct AsyncDebug {
+        pub skip: Option<()>,
+        pub rename: Option<LitStr>,
+        pub name_only: Option<()>,
+        pub transparent: Option<()>,
+    }
+
+    impl FromAttributes for AsyncDebug {
+        const ARGS: &'static [&'static str] = &["skip", "rename", "name_only", "transparent"];
+
+        fn parse_arg(&mut self, arg: &Ident, input: ParseStream) -> Result<bool> {
+            match arg.to_string().as_str() {
+                "skip" => self.skip = Some(()),
+                "rename" => self.rename = Some(parse_value(input)?),
+                "name_only" => self.name_only = Some(()),
+                "transparent" => self.transparent = Some(()),
+                _ => return Ok(false),
+            }
+
+            Ok(true)
+        }
+    }
+
+    impl AsyncDebug {
+        pub fn validate(&self, spanned: &impl Spanned) -> Result<()> {
+            let mut res = Vec::new();
+
+            let exclusive = [self.skip, self.name_only, self.transparent]
+                .iter()
+                .filter(|arg| arg.is_some())
+                .count();
+
+            if exclusive > 1 {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "skip, name_only and transparent are mutually exclusive",
+                )));
+            }
+
+            if self.transparent.is_some() && self.rename.is_some() {
+                res.push(Err(Error::new(
+                    spanned.span(),
+                    "rename can not be used with transparent, which is rendered without a name",
+                )));
+            }
+
+            res.into_iter().collect_syn_error()
+        }
+
+        /// Whether the variant is rendered without any of its fields
+        pub fn is_fieldless(&self) -> bool {
+            self.skip.is_some() || self.name_only.is_some()
+        }
+
+        /// Whether the variant is rendered other than `#[derive(Debug)]` would
+        pub fn is_custom(&self) -> bool {
+            self.is_fieldless() || self.rename.is_some() || self.transparent.is_some()
+        }
+
+        /// Name the variant `ident` is rendered as
+        pub fn name(&self, ident: &Ident) -> String {
+            match &self.rename {
+                Some(rename) => rename.value(),
+                None => ident.unraw().to_string(),
+            }
+        }
+
+        /// `#[serde(rename = ...)]` for the variant of the generated enum, so it is serialized
+        /// under the name it is rendered as
+        pub fn to_token_stream_serde(&self, serde: bool) -> Option<TokenStream> {
+            let rename = self.rename.as_ref().filter(|_| serde)?;
+
+            Some(quote! { #[serde(rename = #rename)] })
+        }
+    }
+
+    pub use AsyncDebug as AsyncDebugAttrVariant;
+}
+
 pub mod attr_struct_enum {
-    use bae::FromAttributes;
+    use proc_macro2::{Ident, TokenStream};
+    use quote::quote;
+    use syn::{
+        parenthesized, parse::ParseStream, parse_quote, punctuated::Punctuated, spanned::Spanned,
+        Expr, LitStr, Path, Token, Type, VisRestricted, Visibility, WherePredicate,
+    };
+
+    use crate::common::{
+        attr_parse::{
+            parse_list, parse_tokens, parse_usize, parse_value, to_token_stream_limits,
+            FromAttributes,
+        },
+        attr_prop::AsyncDebugAttrField,
+        prelude::*,
+    };
 
-    #[derive(FromAttributes, Default)]
+    #[derive(Default)]
     pub struct AsyncDebug {
         pub disable_derive_debug: Option<()>,
+        pub non_exhaustive: Option<()>,
+        pub transparent: Option<()>,
+
+        pub derive: Vec<Path>,
+        pub attr: Vec<TokenStream>,
+
+        pub snapshot: Option<Ident>,
+        pub snapshot_vis: Option<Visibility>,
+        pub module: Option<Ident>,
+
+        pub field_vis: Option<Visibility>,
+        pub getters: Option<()>,
+
+        pub krate: Option<Path>,
+        /// Predicates replacing the inferred ones, like `serde`'s `bound`
+        pub bound: Option<Vec<WherePredicate>>,
+
+        pub send: Option<()>,
+        pub local: Option<()>,
+
+        pub error: Option<Type>,
+
+        /// Each `field(name = "...", ...)`, computed rather than stored
+        pub field: Vec<ComputedField>,
+
+        pub recursive: Option<()>,
+        pub max_depth: Option<usize>,
+        pub max_items: Option<usize>,
+        pub max_len: Option<usize>,
+
+        /// Whether `Serialize` is derived for the generated struct/enum, with the `serde` feature,
+        /// not an argument
+        pub serde: bool,
+    }
+
+    impl FromAttributes for AsyncDebug {
+        const ARGS: &'static [&'static str] = &[
+            "disable_derive_debug",
+            "non_exhaustive",
+            "transparent",
+            "derive",
+            "attr",
+            "snapshot",
+            "snapshot_vis",
+            "module",
+            "field_vis",
+            "getters",
+            "crate",
+            "bound",
+            "send",
+            "local",
+            "error",
+            "field",
+            "recursive",
+            "max_depth",
+            "max_items",
+            "max_len",
+        ];
+
+        fn parse_arg(&mut self, arg: &Ident, input: ParseStream) -> Result<bool> {
+            match arg.to_string().as_str() {
+                "disable_derive_debug" => self.disable_derive_debug = Some(()),
+                "non_exhaustive" => self.non_exhaustive = Some(()),
+                "transparent" => self.transparent = Some(()),
+                "derive" => self.derive.extend(parse_list::<Path>(input)?),
+                "attr" => self.attr.push(parse_tokens(input)?),
+                "snapshot" => self.snapshot = Some(parse_value(input)?),
+                "snapshot_vis" => self.snapshot_vis = Some(parse_value(input)?),
+                "module" => self.module = Some(parse_value(input)?),
+                "field_vis" => self.field_vis = Some(parse_value(input)?),
+                "getters" => self.getters = Some(()),
+                "crate" => self.krate = Some(parse_value::<LitStr>(input)?.parse()?),
+                "bound" => self.bound.get_or_insert_with(Vec::new).extend(
+                    parse_value::<LitStr>(input)?
+                        .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?,
+                ),
+                "send" => self.send = Some(()),
+                "local" => self.local = Some(()),
+                "error" => self.error = Some(parse_value(input)?),
+                "field" => self.field.push(ComputedField::parse(input)?),
+                "recursive" => self.recursive = Some(()),
+                "max_depth" => self.max_depth = Some(parse_usize(input)?),
+                "max_items" => self.max_items = Some(parse_usize(input)?),
+                "max_len" => self.max_len = Some(parse_usize(input)?),
+                _ => return Ok(false),
+            }
+
+            Ok(true)
+        }
+    }
+
+    impl AsyncDebug {
+        pub fn validate(&self) -> Result<()> {
+            let mut res = Vec::new();
+
+            if let (None, Some(snapshot_vis)) = (&self.snapshot, &self.snapshot_vis) {
+                res.push(Err(Error::new(
+                    snapshot_vis.span(),
+                    "snapshot_vis can only be used with snapshot",
+                )));
+            }
+
+            if self.disable_derive_debug.is_some() && self.non_exhaustive.is_some() {
+                res.push(Err(Error::new_call_site(
+                    "non_exhaustive can not be used with disable_derive_debug",
+                )));
+            }
+
+            if self.transparent.is_some() && self.non_exhaustive.is_some() {
+                res.push(Err(Error::new_call_site(
+                    "transparent and non_exhaustive are mutually exclusive",
+                )));
+            }
+
+            if self.send.is_some() && self.local.is_some() {
+                res.push(Err(Error::new_call_site(
+                    "send and local are mutually exclusive",
+                )));
+            }
+
+            if self.transparent.is_some() && !self.field.is_empty() {
+                res.push(Err(Error::new_call_site(
+                    "transparent can not be used with field(...)",
+                )));
+            }
+
+            if self.disable_derive_debug.is_none() {
+                let debug = self.derive.iter().find(|path| {
+                    path.segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "Debug")
+                });
+
+                if let Some(debug) = debug {
+                    res.push(Err(Error::new(
+                        debug.span(),
+                        "Debug is already implemented for the generated struct/enum, unless disable_derive_debug",
+                    )));
+                }
+            }
+
+            for field in &self.field {
+                res.push(field.validate());
+            }
+
+            res.into_iter().collect_syn_error()
+        }
+
+        /// `AsyncDebugContext` builder calls applying `max_depth`, `max_items` and `max_len`
+        pub fn to_token_stream_limits(&self) -> TokenStream {
+            to_token_stream_limits(self.max_depth, self.max_items, self.max_len)
+        }
+
+        /// Path to the `async_debug` crate, `::async_debug` unless overridden with `crate = "..."`
+        pub fn crate_path(&self) -> Path {
+            self.krate
+                .clone()
+                .unwrap_or_else(|| parse_quote! { ::async_debug })
+        }
+
+        /// Resolve a `derive(...)` path, relative to the parent module, from inside the module
+        /// containing the generated struct/enum
+        fn to_token_stream_derive_path(path: &Path) -> TokenStream {
+            const PRELUDE: &[(&str, &str)] = &[
+                ("Clone", "clone"),
+                ("Copy", "marker"),
+                ("Debug", "fmt"),
+                ("Default", "default"),
+                ("Eq", "cmp"),
+                ("Hash", "hash"),
+                ("Ord", "cmp"),
+                ("PartialEq", "cmp"),
+                ("PartialOrd", "cmp"),
+            ];
+
+            let first = match path.segments.first() {
+                Some(first) if path.leading_colon.is_none() => &first.ident,
+                _ => return quote! { #path },
+            };
+
+            if let Some(ident) = path.get_ident() {
+                if let Some((_, module)) = PRELUDE.iter().find(|(name, _)| ident == name) {
+                    let module = Ident::new(module, ident.span());
+                    return quote! { ::core::#module::#ident };
+                }
+
+                return quote! { super::#ident };
+            }
+
+            if first == "self" {
+                let rest = path.segments.iter().skip(1);
+                quote! { super #(::#rest)* }
+            } else if first == "super" {
+                quote! { super::#path }
+            } else {
+                quote! { #path }
+            }
+        }
+
+        /// Resolve a visibility, relative to the parent module, from inside the module containing
+        /// the generated struct/enum
+        pub fn to_token_stream_vis(vis: &Visibility) -> TokenStream {
+            let VisRestricted { path, .. } = match vis {
+                Visibility::Inherited => return quote! { pub(super) },
+                Visibility::Restricted(restricted) => restricted,
+                _ => return quote! { #vis },
+            };
+
+            let first = match path.segments.first() {
+                Some(first) if path.leading_colon.is_none() => &first.ident,
+                _ => return quote! { #vis },
+            };
+
+            if first == "self" {
+                let rest = path.segments.iter().skip(1);
+                quote! { pub(in super #(::#rest)*) }
+            } else if first == "super" {
+                quote! { pub(in super::#path) }
+            } else {
+                quote! { #vis }
+            }
+        }
+
+        /// Visibility of the fields of the generated struct, those of the parent module unless
+        /// overridden with `field_vis = ...`
+        pub fn to_token_stream_field_vis(&self) -> TokenStream {
+            self.field_vis
+                .as_ref()
+                .map_or_else(|| quote! { pub(super) }, Self::to_token_stream_vis)
+        }
+
+        /// `#[derive(...)]` and pass-through attributes for the generated struct/enum, `Debug` is
+        /// not derived with `custom_debug` as it is implemented by `to_token_stream_impl_debug`
+        pub fn to_token_stream_attrs(&self, custom_debug: bool) -> TokenStream {
+            let debug = (self.disable_derive_debug.is_none() && !custom_debug)
+                .then(|| quote! { ::core::fmt::Debug });
+            let krate = self.crate_path();
+            let serialize = self.serde.then(|| quote! { #krate::serde::Serialize });
+            let derives = debug
+                .into_iter()
+                .chain(serialize)
+                .chain(self.derive.iter().map(Self::to_token_stream_derive_path))
+                .collect::<Vec<_>>();
+            let attrs = &self.attr;
+
+            let derive = (!derives.is_empty()).then(|| quote! { #[derive(#(#derives),*)] });
+            let serde = self.serde.then(|| {
+                let serde_crate = quote! { #krate::serde }.to_string().replace(' ', "");
+                let transparent = self.transparent.map(|()| quote! { , transparent });
+                quote! { #[serde(crate = #serde_crate #transparent)] }
+            });
+
+            quote! {
+                #derive
+                #serde
+                #(#[#attrs])*
+            }
+        }
+    }
+
+    /// `field(name = "...", call = some_function, ...)`, a field of the generated struct that is
+    /// not stored, but the value of `call`, or of `async_call` awaited, on a reference to `self`.
+    /// The remaining arguments are those of a field.
+    #[derive(Default)]
+    pub struct ComputedField {
+        pub name: Option<Ident>,
+        pub call: Option<Expr>,
+        /// Field of the struct it follows, it is appended after the stored ones otherwise
+        pub after: Option<Ident>,
+        attr: AsyncDebugAttrField,
+    }
+
+    impl FromAttributes for ComputedField {
+        const ARGS: &'static [&'static str] = &["name", "call", "after"];
+
+        fn supported_args() -> Vec<&'static str> {
+            Self::ARGS
+                .iter()
+                .chain(AsyncDebugAttrField::ARGS)
+                .copied()
+                .collect()
+        }
+
+        fn parse_arg(&mut self, arg: &Ident, input: ParseStream) -> Result<bool> {
+            match arg.to_string().as_str() {
+                "name" => self.name = Some(parse_value::<LitStr>(input)?.parse()?),
+                "call" => self.call = Some(parse_value(input)?),
+                "after" => self.after = Some(parse_value::<LitStr>(input)?.parse()?),
+                _ => return self.attr.parse_arg(arg, input),
+            }
+
+            Ok(true)
+        }
+    }
+
+    impl ComputedField {
+        /// `field(arg, arg, ...)`
+        fn parse(input: ParseStream) -> Result<Self> {
+            let content;
+            parenthesized!(content in input);
+
+            let mut field = Self::default();
+            field.parse_args(&content)?;
+
+            Ok(field)
+        }
+
+        /// Arguments of this field, `call` is a `map` of `self`
+        pub fn attr(&self) -> AsyncDebugAttrField {
+            AsyncDebugAttrField {
+                map: self.call.clone().or_else(|| self.attr.map.clone()),
+                ..self.attr.clone()
+            }
+        }
+
+        fn validate(&self) -> Result<()> {
+            let Some(name) = &self.name else {
+                return Err(Error::new_call_site("field(...) requires name"));
+            };
+            let attr = &self.attr;
+            let mut res = Vec::new();
+
+            if self.call.is_some() == attr.async_call.is_some() {
+                res.push(Err(Error::new(
+                    name.span(),
+                    "field(...) requires either call or async_call, whose result is its value",
+                )));
+            }
+
+            if self.call.is_some() && attr.map.is_some() {
+                res.push(Err(Error::new(
+                    name.span(),
+                    "call and map are mutually exclusive, map converts the result of async_call",
+                )));
+            }
+
+            if attr.skip.is_some()
+                || attr.flatten.is_some()
+                || attr.optional.is_some()
+                || attr.upgrade.is_some()
+            {
+                res.push(Err(Error::new(
+                    name.span(),
+                    "field(...) can not contain skip, flatten, optional or upgrade",
+                )));
+            }
+
+            if !attr.has_known_ty() && !attr.renders_within_context() {
+                res.push(Err(Error::new(
+                    name.span(),
+                    "field(...) requires ty, to_string, into, nested or truncate, the type of its value can not be inferred",
+                )));
+            }
+
+            if res.is_empty() {
+                res.push(self.attr().validate(name));
+            }
+
+            res.into_iter().collect_syn_error()
+        }
     }
 
     pub use self::AsyncDebug as AsyncDebugAttrStructEnum;
@@ -105,18 +1719,20 @@ pub mod prelude {
     where
         I: std::iter::Iterator<Item = Result<T>>,
     {
-        fn collect_syn_error<B: FromIterator<T>>(self) -> Result<B>
+        fn collect_syn_error<B>(self) -> Result<B>
         where
-            B: Default;
+            B: FromIterator<T> + Default;
     }
 
     impl<T, I> IterCombineSynErrors<T, I> for I
     where
         I: std::iter::Iterator<Item = Result<T>>,
     {
-        fn collect_syn_error<B: FromIterator<T>>(self) -> Result<B>
+        // `try_fold` would stop at the first error instead of combining them all
+        #[allow(clippy::manual_try_fold)]
+        fn collect_syn_error<B>(self) -> Result<B>
         where
-            B: Default,
+            B: FromIterator<T> + Default,
         {
             let res_vec =
                 self.fold::<Result<Vec<T>>, _>(Ok(Default::default()), |accum, res| {
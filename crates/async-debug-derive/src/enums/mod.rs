@@ -5,13 +5,13 @@ use indexmap::IndexMap;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::{
-    DeriveInput, Error, Fields, FieldsNamed, FieldsUnnamed, GenericArgument, ImplGenerics,
-    TypeGenerics, Variant, Visibility, WhereClause,
+    DeriveInput, Error, Fields, FieldsNamed, FieldsUnnamed, GenericArgument, Generics,
+    ImplGenerics, Lifetime, Path, Type, TypeGenerics, Variant, Visibility,
 };
 
 use crate::{
     common::{attr_struct_enum::AsyncDebugAttrStructEnum, prelude::*, AsyncDebugCommon},
-    fields::AsyncDebugFields,
+    fields::{AsyncDebugField, AsyncDebugFields, AsyncDebugFieldsMap},
 };
 
 use self::{named::AsyncDebugVariantNamed, unnamed::AsyncDebugVariantUnnamed};
@@ -20,9 +20,9 @@ pub struct AsyncDebugEnum<'a> {
     vis: Visibility,
     ident: Ident,
     mod_ident: Ident,
+    generics: &'a Generics,
     generics_impl: ImplGenerics<'a>,
     generics_ty: TypeGenerics<'a>,
-    where_clause: Option<&'a WhereClause>,
     variants: IndexMap<Ident, AsyncDebugVariant>,
     attr: AsyncDebugAttrStructEnum,
 }
@@ -30,44 +30,68 @@ pub struct AsyncDebugEnum<'a> {
 impl<'a> AsyncDebugCommon for AsyncDebugEnum<'a> {}
 
 impl<'a> AsyncDebugEnum<'a> {
-    pub fn new(input: &'a DeriveInput, variants: Vec<Variant>) -> Result<Self> {
-        let mod_ident = Self::get_async_debug_mod_ident(&input.ident);
-        let attr = Self::get_attr_struct_enum(&input.attrs)?;
+    pub fn new(input: &'a DeriveInput, variants: Vec<Variant>, serde: bool) -> Result<Self> {
+        let attr = Self::get_attr_struct_enum(&input.attrs, serde)?;
+
+        if attr.transparent.is_some() {
+            return Err(Error::new_call_site(
+                "transparent can only be used on structs",
+            ));
+        }
+
+        if !attr.field.is_empty() {
+            return Err(Error::new_call_site(
+                "field(...) can only be used on structs with named fields",
+            ));
+        }
+
+        if attr.field_vis.is_some() || attr.getters.is_some() {
+            return Err(Error::new_call_site(
+                "field_vis and getters can only be used on structs",
+            ));
+        }
+
+        let mod_ident = Self::get_async_debug_mod_ident(&input.ident, &attr);
 
         let variants = variants
             .iter()
-            .map(|variant| {
+            .enumerate()
+            .map(|(index, variant)| {
                 let ident = &variant.ident;
 
                 Ok((
                     ident.clone(),
-                    AsyncDebugVariant::new(variant.clone(), input.ident.clone())?,
+                    AsyncDebugVariant::new(variant.clone(), index, input.ident.clone(), &attr)?,
                 ))
             })
             .collect_syn_error()?;
 
-        let (generics_impl, generics_ty, where_clause) = input.generics.split_for_impl();
+        let (generics_impl, generics_ty, _) = input.generics.split_for_impl();
 
         Ok(Self {
             vis: input.vis.clone(),
             ident: input.ident.clone(),
             mod_ident,
+            generics: &input.generics,
             generics_impl,
             generics_ty,
-            where_clause,
             variants,
             attr,
         })
     }
 
-    fn get_new_generics(&self) -> Result<(Vec<GenericArgument>, Vec<GenericArgument>)> {
+    fn get_new_generics(
+        &self,
+        lifetime: Option<&Lifetime>,
+        propagate: bool,
+    ) -> Result<(Vec<GenericArgument>, Vec<GenericArgument>)> {
         let mut names = Vec::new();
         let mut types = Vec::new();
 
         let iter = self
             .variants
             .values()
-            .map(|variant| variant.get_new_generics())
+            .map(|variant| variant.get_new_generics(lifetime, propagate))
             .collect::<Result<Vec<_>>>()?;
 
         for (variant_names, variant_types) in iter {
@@ -78,6 +102,21 @@ impl<'a> AsyncDebugEnum<'a> {
         Ok((names, types))
     }
 
+    fn get_field_types(&self) -> Vec<&Type> {
+        self.variants
+            .values()
+            .flat_map(|variant| variant.get_field_types())
+            .collect()
+    }
+
+    /// Fields of every variant
+    fn fields(&self) -> impl Iterator<Item = &AsyncDebugField> {
+        self.variants
+            .values()
+            .filter_map(|variant| variant.get_fields())
+            .flat_map(|fields| fields.values())
+    }
+
     fn get_variants(&self) -> Result<TokenStream> {
         self.variants
             .values()
@@ -85,15 +124,32 @@ impl<'a> AsyncDebugEnum<'a> {
             .collect()
     }
 
-    pub fn to_token_stream_impl_ident_bodies(&self) -> Result<TokenStream> {
+    /// Match arms of `self` for every variant, see `to_token_stream_impl_ident_body`
+    pub fn to_token_stream_impl_ident_bodies(
+        &self,
+        rendered: bool,
+        propagate: bool,
+    ) -> Result<TokenStream> {
+        let krate = self.attr.crate_path();
+
         self.variants
             .values()
-            .map(|variant| variant.to_token_stream_impl_ident_body(&self.mod_ident))
+            .map(|variant| {
+                variant.to_token_stream_impl_ident_body(
+                    &self.mod_ident,
+                    &krate,
+                    rendered,
+                    propagate,
+                )
+            })
             .collect()
     }
 
     pub fn to_token_stream(&self) -> Result<TokenStream> {
-        let (new_generics_names, new_generics) = self.get_new_generics()?;
+        let (new_generics_names, new_generics) = self.get_new_generics(None, false)?;
+        let (_, new_generics_lifetime) =
+            self.get_new_generics(Some(&Self::get_snapshot_lifetime(self.generics)), false)?;
+        let (_, new_generics_try) = self.get_new_generics(None, true)?;
         let variants = self.get_variants()?;
 
         let vis = &self.vis;
@@ -102,40 +158,142 @@ impl<'a> AsyncDebugEnum<'a> {
 
         let generics_impl = &self.generics_impl;
         let generics_ty = &self.generics_ty;
-        let where_clause = &self.where_clause;
 
-        let ts_impl_async_debug = quote! {
-            impl #generics_impl AsyncDebug for #ident #generics_ty #where_clause {}
+        let type_params = Self::get_type_params(self.generics);
+        let where_clause = Self::get_where_clause(
+            self.generics,
+            &self.attr,
+            self.fields()
+                .filter_map(|field| field.context_predicate(&type_params))
+                .collect(),
+        );
+        let context = self.fields().any(|field| field.uses_snapshot_context());
+        let recursive =
+            self.attr.recursive.is_some() || self.fields().any(|field| field.is_recursive(ident));
+
+        let ts_impl_async_debug = Self::to_token_stream_impl_async_debug(
+            &self.attr,
+            vis,
+            ident,
+            self.generics,
+            &where_clause,
+            quote! { #mod_ident::#ident <#(#new_generics_lifetime),*> },
+        );
+
+        let token_stream_impl_ident_bodies =
+            self.to_token_stream_impl_ident_bodies(false, false)?;
+        let token_stream_impl_ident_bodies_try =
+            self.to_token_stream_impl_ident_bodies(false, true)?;
+        let token_stream_impl_ident_bodies_rendered =
+            self.to_token_stream_impl_ident_bodies(true, false)?;
+
+        let output = quote! { #mod_ident::#ident <#(#new_generics),*> };
+        let body = quote! {
+            match self {
+                #token_stream_impl_ident_bodies
+            }
+        };
+        let output_try = quote! { #mod_ident::#ident <#(#new_generics_try),*> };
+        let body_try = quote! {
+            match self {
+                #token_stream_impl_ident_bodies_try
+            }
         };
 
-        let token_stream_impl_ident_bodies = self.to_token_stream_impl_ident_bodies()?;
+        let body_rendered = quote! {
+            match self {
+                #token_stream_impl_ident_bodies_rendered
+            }
+        };
+        let rendered_predicates = Self::get_rendered_predicates(
+            &self.attr,
+            self.fields()
+                .flat_map(|field| field.rendered_predicates(&type_params)),
+        );
+
+        let ts_fn_async_debug = Self::to_token_stream_fn_async_debug(
+            &self.attr,
+            vis,
+            self.get_field_types(),
+            context,
+            output,
+            body,
+        );
+
+        let ts_fn_try_async_debug = Self::to_token_stream_fn_try_async_debug(
+            &self.attr,
+            vis,
+            self.get_field_types(),
+            context,
+            output_try,
+            body_try,
+        );
+
+        let ts_fn_async_debug_with = Self::to_token_stream_fn_async_debug_with(
+            &self.attr,
+            vis,
+            self.generics,
+            Self::extend_where_clause(None, rendered_predicates.clone()),
+            self.get_field_types(),
+        );
+
+        let ts_fn_async_debug_profiles = Self::to_token_stream_fn_async_debug_profiles(
+            &self.attr,
+            vis,
+            Self::extend_where_clause(None, rendered_predicates.clone()),
+            Self::get_profiles(self.fields()),
+        );
 
         let ts_impl_ident = quote! {
             #[automatically_derived]
             impl #generics_impl #ident #generics_ty #where_clause {
-                #vis async fn async_debug (&self) -> #mod_ident::#ident <#(#new_generics),*>
-                #where_clause
-                {
-                    match self {
-                        #token_stream_impl_ident_bodies
-                    }
-                }
+                #ts_fn_async_debug
+                #ts_fn_try_async_debug
+                #ts_fn_async_debug_with
+                #ts_fn_async_debug_profiles
             }
         };
 
-        let derive = {
-            if self.attr.disable_derive_debug.is_none() {
-                Some(quote! { #[derive(Debug)] })
-            } else {
-                None
-            }
+        let ts_impl_async_debug_nested = Self::to_token_stream_impl_async_debug_nested(
+            &self.attr,
+            ident,
+            self.generics,
+            &Self::extend_where_clause(where_clause.clone(), rendered_predicates),
+            recursive,
+            body_rendered,
+        );
+
+        let custom_debug = self
+            .variants
+            .values()
+            .any(|variant| variant.is_custom_debug());
+        let attrs = self.attr.to_token_stream_attrs(custom_debug);
+        let ts_impl_debug = if custom_debug {
+            let arms = self
+                .variants
+                .values()
+                .map(|variant| variant.to_token_stream_debug_arm())
+                .collect::<Result<Vec<_>>>()?;
+
+            Self::to_token_stream_impl_debug(
+                &self.attr,
+                ident,
+                &new_generics_names,
+                &None,
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                },
+            )
+        } else {
+            None
         };
 
         let ts_enum = quote! {
+            #[allow(non_snake_case)]
             #vis mod #mod_ident {
-                use super::*;
-
-                #derive
+                #attrs
                 #[allow(dead_code)]
                 #[allow(non_camel_case_types)]
                 #[automatically_derived]
@@ -143,12 +301,15 @@ impl<'a> AsyncDebugEnum<'a> {
                 {
                     #variants
                 }
+
+                #ts_impl_debug
             }
         };
 
         Ok(quote! {
             #ts_impl_async_debug
             #ts_impl_ident
+            #ts_impl_async_debug_nested
             #ts_enum
         })
     }
@@ -161,15 +322,22 @@ enum AsyncDebugVariant {
 }
 
 impl AsyncDebugVariant {
-    pub fn new(variant: Variant, enum_debug_ident: Ident) -> Result<Self> {
+    pub fn new(
+        variant: Variant,
+        index: usize,
+        enum_debug_ident: Ident,
+        attr: &AsyncDebugAttrStructEnum,
+    ) -> Result<Self> {
         Ok(match &variant.fields {
             Fields::Named(FieldsNamed { named: fields, .. }) => {
                 let fields = fields.iter().cloned().collect::<Vec<_>>();
 
                 Self::Named(AsyncDebugVariantNamed::new(
                     variant,
+                    index,
                     enum_debug_ident,
                     fields,
+                    attr,
                 )?)
             }
             Fields::Unit => Self::Unit,
@@ -180,26 +348,76 @@ impl AsyncDebugVariant {
 
                 Self::Unnamed(AsyncDebugVariantUnnamed::new(
                     variant,
+                    index,
                     enum_debug_ident,
                     fields,
+                    attr,
                 )?)
             }
         })
     }
 
-    fn get_new_generics(&self) -> Result<(Vec<GenericArgument>, Vec<GenericArgument>)> {
+    fn get_new_generics(
+        &self,
+        lifetime: Option<&Lifetime>,
+        propagate: bool,
+    ) -> Result<(Vec<GenericArgument>, Vec<GenericArgument>)> {
         match self {
-            Self::Named(named) => named.get_new_generics(),
+            Self::Named(named) => named.get_new_generics(lifetime, propagate),
             Self::Unit => Err(Error::new_call_site("unreachable")),
-            Self::Unnamed(unnamed) => unnamed.get_new_generics(),
+            Self::Unnamed(unnamed) => unnamed.get_new_generics(lifetime, propagate),
         }
     }
 
-    fn to_token_stream_impl_ident_body(&self, mod_ident: &Ident) -> Result<TokenStream> {
+    fn get_fields(&self) -> Option<&AsyncDebugFieldsMap> {
         match self {
-            Self::Named(named) => named.to_token_stream_impl_ident_body(mod_ident),
+            Self::Named(named) => Some(named.get_fields()),
+            Self::Unit => None,
+            Self::Unnamed(unnamed) => Some(unnamed.get_fields()),
+        }
+    }
+
+    fn get_field_types(&self) -> Vec<&Type> {
+        match self {
+            Self::Named(named) => named.get_field_types(),
+            Self::Unit => Vec::new(),
+            Self::Unnamed(unnamed) => unnamed.get_field_types(),
+        }
+    }
+
+    fn to_token_stream_impl_ident_body(
+        &self,
+        mod_ident: &Ident,
+        krate: &Path,
+        rendered: bool,
+        propagate: bool,
+    ) -> Result<TokenStream> {
+        match self {
+            Self::Named(named) => {
+                named.to_token_stream_impl_ident_body(mod_ident, krate, rendered, propagate)
+            }
             Self::Unit => Err(Error::new_call_site("unreachable")),
-            Self::Unnamed(unnamed) => unnamed.to_token_stream_impl_ident_body(mod_ident),
+            Self::Unnamed(unnamed) => {
+                unnamed.to_token_stream_impl_ident_body(mod_ident, krate, rendered, propagate)
+            }
+        }
+    }
+
+    /// Whether this variant of the generated enum needs the generated `Debug` impl, see
+    /// `to_token_stream_impl_debug`
+    fn is_custom_debug(&self) -> bool {
+        match self {
+            Self::Named(named) => named.is_custom_debug(),
+            Self::Unit => false,
+            Self::Unnamed(unnamed) => unnamed.is_custom_debug(),
+        }
+    }
+
+    fn to_token_stream_debug_arm(&self) -> Result<TokenStream> {
+        match self {
+            Self::Named(named) => named.to_token_stream_debug_arm(),
+            Self::Unit => Ok(quote! {}),
+            Self::Unnamed(unnamed) => unnamed.to_token_stream_debug_arm(),
         }
     }
 
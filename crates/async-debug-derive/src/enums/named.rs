@@ -1,9 +1,12 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{Field, Variant};
+use syn::{Field, Path, Variant};
 
 use crate::{
-    common::prelude::*,
+    common::{
+        attr_parse::FromAttributes, attr_struct_enum::AsyncDebugAttrStructEnum,
+        attr_variant::AsyncDebugAttrVariant, prelude::*, AsyncDebugCommon,
+    },
     fields::{AsyncDebugFields, AsyncDebugFieldsMap},
 };
 
@@ -11,8 +14,15 @@ pub struct AsyncDebugVariantNamed {
     variant: Variant,
     enum_ident: Ident,
     fields: AsyncDebugFieldsMap,
+    variant_attr: AsyncDebugAttrVariant,
+    /// See `get_non_exhaustive`
+    non_exhaustive: Option<bool>,
+    /// Whether `Serialize` is derived for the generated enum
+    serde: bool,
 }
 
+impl AsyncDebugCommon for AsyncDebugVariantNamed {}
+
 impl AsyncDebugFields for AsyncDebugVariantNamed {
     fn get_fields(&self) -> &AsyncDebugFieldsMap {
         &self.fields
@@ -20,36 +30,172 @@ impl AsyncDebugFields for AsyncDebugVariantNamed {
 }
 
 impl AsyncDebugVariantNamed {
-    pub fn new(variant: Variant, enum_ident: Ident, fields: Vec<Field>) -> Result<Self> {
-        Ok(Self {
-            fields: Self::convert_fields(fields.iter().collect(), Some(variant.ident.clone()))?,
+    pub fn new(
+        variant: Variant,
+        variant_index: usize,
+        enum_ident: Ident,
+        fields: Vec<Field>,
+        attr: &AsyncDebugAttrStructEnum,
+    ) -> Result<Self> {
+        let variant_attr =
+            AsyncDebugAttrVariant::try_from_attributes(&variant.attrs)?.unwrap_or_default();
+        variant_attr.validate(&variant.ident)?;
+
+        // The fields of a `skip` or `name_only` variant are neither part of the generated enum
+        // nor rendered
+        let fields = if variant_attr.is_fieldless() {
+            Vec::new()
+        } else {
+            fields
+        };
+
+        let variant = Self {
+            fields: Self::convert_fields(
+                fields.iter().collect(),
+                Some((variant_index, variant.ident.clone())),
+                attr,
+            )?,
+            non_exhaustive: Self::get_non_exhaustive(&variant.attrs, attr),
+            variant_attr,
             variant,
             enum_ident,
-        })
+            serde: attr.serde,
+        };
+        variant.get_transparent_field(variant.variant_attr.transparent, "variant")?;
+
+        Ok(variant)
     }
 
-    pub fn to_token_stream_impl_ident_body(&self, mod_ident: &Ident) -> Result<TokenStream> {
+    /// Match arm of `self` for this variant, evaluating to the generated enum, or to its
+    /// rendering with `rendered`, with `propagate` to that of `try_async_debug`
+    pub fn to_token_stream_impl_ident_body(
+        &self,
+        mod_ident: &Ident,
+        krate: &Path,
+        rendered: bool,
+        propagate: bool,
+    ) -> Result<TokenStream> {
         let ident = &self.variant.ident;
         let enum_ident = &self.enum_ident;
-        let field_idents = self.fields.keys().collect::<Vec<_>>();
+        let name = self.variant_attr.name(ident);
+        let field_patterns = self
+            .fields
+            .values()
+            .filter_map(|field| field.to_token_stream_pattern());
+
+        if rendered {
+            let body = match self.get_transparent_field(self.variant_attr.transparent, "variant")? {
+                Some(field) => field.to_token_stream_rendered_transparent(None),
+                None => self.to_token_stream_impl_ident_body_rendered(
+                    None,
+                    krate,
+                    &name,
+                    false,
+                    self.get_variant_non_exhaustive(),
+                ),
+            };
+
+            return Ok(quote! {
+                Self::#ident { #(#field_patterns,)* .. } => #body,
+            });
+        }
+
+        if self.variant_attr.is_fieldless() {
+            let fieldless = self.to_token_stream_fieldless();
+
+            return Ok(quote! {
+                Self::#ident { .. } => #mod_ident::#enum_ident::#ident #fieldless,
+            });
+        }
 
         let token_stream_impl_ident_body =
-            <Self as AsyncDebugFields>::to_token_stream_impl_ident_body(self, None)?;
+            <Self as AsyncDebugFields>::to_token_stream_impl_ident_body(self, None, propagate)?;
 
         Ok(quote! {
-            Self::#ident { #(#field_idents),* } => #mod_ident::#enum_ident::#ident {
+            Self::#ident { #(#field_patterns,)* .. } => #mod_ident::#enum_ident::#ident {
                 #token_stream_impl_ident_body
             },
         })
     }
 
+    /// `non_exhaustive` as rendered, `skip` renders the variant followed by `..` and `name_only`
+    /// without
+    fn get_variant_non_exhaustive(&self) -> Option<bool> {
+        if self.variant_attr.is_fieldless() {
+            Some(self.variant_attr.skip.is_some())
+        } else {
+            self.non_exhaustive
+        }
+    }
+
+    pub fn is_custom_debug(&self) -> bool {
+        self.variant_attr.is_custom()
+            || self.has_skippable_fields()
+            || self.is_non_exhaustive(self.non_exhaustive)
+    }
+
+    /// Match arm of the generated `Debug` impl for this variant of the generated enum
+    pub fn to_token_stream_debug_arm(&self) -> Result<TokenStream> {
+        let ident = &self.variant.ident;
+        let name = self.variant_attr.name(ident);
+
+        if self.variant_attr.is_fieldless() {
+            let body = Self::to_token_stream_debug_body(
+                &name,
+                false,
+                self.is_non_exhaustive(self.get_variant_non_exhaustive()),
+                quote! {},
+            );
+
+            return Ok(quote! {
+                Self::#ident { .. } => #body,
+            });
+        }
+
+        let field_patterns = self
+            .fields
+            .values()
+            .filter(|field| field.attr.skip.is_none())
+            .filter_map(|field| field.to_token_stream_pattern());
+        let body = match self.get_transparent_field(self.variant_attr.transparent, "variant")? {
+            Some(field) => {
+                Self::to_token_stream_debug_transparent(field.to_token_stream_self(None))
+            }
+            None => Self::to_token_stream_debug_body(
+                &name,
+                false,
+                self.is_non_exhaustive(self.non_exhaustive),
+                self.to_token_stream_debug_fields(None),
+            ),
+        };
+
+        Ok(quote! {
+            Self::#ident { #(#field_patterns),* } => #body,
+        })
+    }
+
+    /// Empty braces of a `skip` variant of the generated enum, so it is serialized like a variant
+    /// whose fields were all left out, as it is rendered, unlike a `name_only` one
+    fn to_token_stream_fieldless(&self) -> Option<TokenStream> {
+        self.variant_attr.skip.map(|()| quote! { {} })
+    }
+
     pub fn to_token_stream(&self) -> Result<TokenStream> {
         let ident = &self.variant.ident;
+        let serde = self.variant_attr.to_token_stream_serde(self.serde);
+
+        if self.variant_attr.is_fieldless() {
+            let fieldless = self.to_token_stream_fieldless();
+
+            return Ok(quote! {
+                #serde #ident #fieldless,
+            });
+        }
 
-        let fields_type = self.get_fields_type();
+        let fields_type = self.get_fields_type(None);
 
         Ok(quote! {
-            #ident {
+            #serde #ident {
                 #fields_type
             },
         })
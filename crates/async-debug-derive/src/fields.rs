@@ -1,12 +1,20 @@
 use std::num::TryFromIntError;
 
 use indexmap::IndexMap;
-use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote, IdentFragment, ToTokens};
-use syn::{parse2, spanned::Spanned, Error, Field, GenericArgument, Index, Type};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, IdentFragment, ToTokens};
+use syn::{
+    ext::IdentExt, parse2, parse_quote, spanned::Spanned, Error, Expr, Field, GenericArgument,
+    Index, Lifetime, Path, PathArguments, Type, Visibility, WherePredicate,
+};
 
 use crate::{
-    common::{attr_prop::AsyncDebugAttrField, prelude::*},
+    common::{
+        attr_parse::FromAttributes,
+        attr_prop::{AsyncCallStep, AsyncDebugAttrField},
+        attr_struct_enum::{AsyncDebugAttrStructEnum, ComputedField},
+        prelude::*,
+    },
     zip_result::ZipResult,
 };
 
@@ -17,25 +25,81 @@ pub trait AsyncDebugFields {
 
     fn convert_fields(
         fields: Vec<&Field>,
-        variant_ident: Option<Ident>,
+        variant: Option<(usize, Ident)>,
+        attr: &AsyncDebugAttrStructEnum,
     ) -> Result<AsyncDebugFieldsMap> {
         fields
             .into_iter()
             .cloned()
             .enumerate()
             .map(|(index, field)| {
-                AsyncDebugField::new(field, variant_ident.clone(), index)
+                AsyncDebugField::new(field, variant.clone(), index, attr)
                     .map(|field| (field.ident.clone(), field))
             })
             .collect_syn_error()
     }
 
-    fn get_new_generics(&self) -> Result<(Vec<GenericArgument>, Vec<GenericArgument>)> {
+    /// `fields` with each `field(...)` of `attr` inserted after the field it follows, or
+    /// appended, in order
+    fn insert_computed_fields(
+        fields: AsyncDebugFieldsMap,
+        attr: &AsyncDebugAttrStructEnum,
+    ) -> Result<AsyncDebugFieldsMap> {
+        let mut fields = fields.into_iter().collect::<Vec<_>>();
+
+        for computed in &attr.field {
+            let field = AsyncDebugField::computed(computed, attr)?;
+
+            if fields.iter().any(|(ident, _)| *ident == field.ident) {
+                return Err(Error::new(
+                    field.field.span(),
+                    "field(...) can not be named like another field",
+                ));
+            }
+
+            let mut index = match &computed.after {
+                Some(after) => {
+                    fields
+                        .iter()
+                        .position(|(ident, _)| {
+                            matches!(ident, AsyncDebugFieldIdent::Ident(ident) if ident == after)
+                        })
+                        .ok_or_else(|| {
+                            Error::new(after.span(), format!("no field `{after}` to follow"))
+                        })?
+                        + 1
+                }
+                None => fields.len(),
+            };
+
+            // Following those computed fields already inserted after the same one
+            while fields.get(index).is_some_and(|(_, field)| field.computed) {
+                index += 1;
+            }
+
+            fields.insert(index, (field.ident.clone(), field));
+        }
+
+        Ok(fields.into_iter().collect())
+    }
+
+    /// Generic parameters of the generated struct/enum and their types, for every field other
+    /// than skipped ones. With `propagate` the types are those of `try_async_debug`, see
+    /// `AsyncDebugField::ty`.
+    fn get_new_generics(
+        &self,
+        lifetime: Option<&Lifetime>,
+        propagate: bool,
+    ) -> Result<(Vec<GenericArgument>, Vec<GenericArgument>)> {
         let (names, types): (Vec<GenericArgument>, Vec<Type>) = self
             .get_fields()
             .values()
             .filter(|field| field.attr.skip.is_none())
-            .map(|field| field.generic_argument().zip_result(field.ty()))
+            .map(|field| {
+                field
+                    .generic_argument()
+                    .zip_result(field.ty(lifetime, propagate))
+            })
             .collect::<Result<Vec<_>>>()?
             .into_iter()
             .unzip();
@@ -48,50 +112,471 @@ pub trait AsyncDebugFields {
         Ok((names, types))
     }
 
-    fn get_fields_type(&self) -> TokenStream {
+    /// Types of every stored field, including skipped ones as they are still borrowed through
+    /// `self`
+    fn get_field_types(&self) -> Vec<&Type> {
+        self.get_fields()
+            .values()
+            .filter(|field| !field.computed)
+            .map(|field| &field.field.ty)
+            .collect()
+    }
+
+    /// Fields of the generated struct/enum, with `vis` those of a struct, the fields of enum
+    /// variants have none
+    fn get_fields_type(&self, vis: Option<&TokenStream>) -> TokenStream {
         self.get_fields()
             .values()
             .filter(|field| field.attr.skip.is_none())
             .map(|field| {
                 let ident = &field.ident;
-                let generic_argument = field.generic_argument_ident();
+                let generic_argument = field.to_token_stream_generated_ty();
+                let attrs = &field.attr.attr;
 
-                let vis = {
-                    if field.variant_ident.is_none() {
-                        Some(quote! { pub(super) })
-                    } else {
-                        None
+                match ident {
+                    AsyncDebugFieldIdent::Ident(ident) => {
+                        let serde = if !field.serde {
+                            None
+                        } else if field.attr.flatten.is_some() {
+                            Some(quote! { #[serde(flatten)] })
+                        } else if field.attr.is_skippable() {
+                            Some(quote! { #[serde(skip_serializing_if = "Option::is_none")] })
+                        } else {
+                            None
+                        };
+
+                        quote! { #serde #(#[#attrs])* #vis #ident: #generic_argument, }
+                    }
+                    AsyncDebugFieldIdent::Index(_) => {
+                        quote! { #(#[#attrs])* #vis #generic_argument, }
                     }
+                }
+            })
+            .collect()
+    }
+
+    /// Inherent `impl` of the generated struct with a method borrowing each of its named fields,
+    /// with the visibility `vis`
+    fn to_token_stream_getters(
+        &self,
+        ident: &Ident,
+        generics_names: &[GenericArgument],
+        vis: &TokenStream,
+    ) -> Result<TokenStream> {
+        let getters = self
+            .get_fields()
+            .values()
+            .filter(|field| field.attr.skip.is_none())
+            .map(|field| {
+                let AsyncDebugFieldIdent::Ident(ident) = &field.ident else {
+                    return Err(Error::new_call_site(
+                        "getters is only supported on structs with named fields",
+                    ));
                 };
+                let ty = field.to_token_stream_generated_ty();
 
-                match ident {
-                    AsyncDebugFieldIdent::Ident(ident) => {
-                        quote! { #vis #ident: #generic_argument, }
+                Ok(quote! {
+                    #vis fn #ident(&self) -> &#ty {
+                        &self.#ident
+                    }
+                })
+            })
+            .collect_syn_error::<Vec<_>>()?;
+
+        Ok(quote! {
+            #[allow(non_camel_case_types)]
+            #[automatically_derived]
+            impl <#(#generics_names),*> #ident <#(#generics_names),*> {
+                #(#getters)*
+            }
+        })
+    }
+
+    /// The only field rendered, when the `container` struct/variant is `transparent`, which it is
+    /// rendered as
+    fn get_transparent_field(
+        &self,
+        transparent: Option<()>,
+        container: &str,
+    ) -> Result<Option<&AsyncDebugField>> {
+        if transparent.is_none() {
+            return Ok(None);
+        }
+
+        let mut fields = self
+            .get_fields()
+            .values()
+            .filter(|field| field.attr.skip.is_none());
+
+        let field = match (fields.next(), fields.next()) {
+            (Some(field), None) => field,
+            _ => {
+                return Err(Error::new_call_site(format!(
+                    "transparent can only be used on {container}s with a single field, other than skipped ones",
+                )))
+            }
+        };
+
+        if field.attr.flatten.is_some() {
+            return Err(Error::new(
+                field.field.span(),
+                format!("the field of a transparent {container} can not be flattened"),
+            ));
+        }
+
+        if field.attr.is_skippable() || field.profiles().any(|(_, field)| field.attr.skip.is_some())
+        {
+            return Err(Error::new(
+                field.field.span(),
+                format!("the field of a transparent {container} can not be skipped, with skip_if, skip_if_empty or in a profile"),
+            ));
+        }
+
+        Ok(Some(field))
+    }
+
+    /// Whether any field may be absent from the generated struct/enum, which then implements
+    /// `Debug` itself to leave them out
+    fn has_skippable_fields(&self) -> bool {
+        self.get_fields()
+            .values()
+            .any(|field| field.attr.skip.is_none() && field.attr.is_skippable())
+    }
+
+    /// Whether the generated struct/enum, or this variant of it, is rendered followed by `..`,
+    /// given the `non_exhaustive` of `get_non_exhaustive`, as it is declared `#[non_exhaustive]`
+    /// or has skipped fields
+    fn is_non_exhaustive(&self, non_exhaustive: Option<bool>) -> bool {
+        non_exhaustive.is_some_and(|declared| {
+            declared
+                || self
+                    .get_fields()
+                    .values()
+                    .any(|field| field.attr.skip.is_some())
+        })
+    }
+
+    /// Statements writing every field of the generated struct/enum to `debug`, a `DebugStruct`
+    /// or `DebugTuple`, leaving out those skipped by `skip_if`. Those of `flatten`ed fields are
+    /// written through `DebugFields`, as are those of a struct that may itself be flattened, so
+    /// `debug` is then a `&mut DebugStruct`.
+    fn to_token_stream_debug_fields(&self, prefix: Option<TokenStream>) -> TokenStream {
+        let debug = AsyncDebugField::debug_ident();
+        let value = Ident::new("value", Span::mixed_site());
+
+        self.get_fields()
+            .values()
+            .filter(|field| field.attr.skip.is_none())
+            .map(|field| {
+                let this = if field.computed {
+                    let ident = &field.ident;
+                    quote! { #prefix #ident }
+                } else {
+                    field.to_token_stream_self(prefix.clone())
+                };
+
+                if field.attr.flatten.is_some() {
+                    let krate = &field.krate;
+
+                    return quote! {
+                        #krate::DebugFields::debug_fields(&#this, #debug);
+                    };
+                }
+
+                let write = match &field.ident {
+                    AsyncDebugFieldIdent::Ident(_) => {
+                        let name = field.name();
+                        quote! { #debug.field(#name, #value); }
+                    }
+                    AsyncDebugFieldIdent::Index(_) => quote! { #debug.field(#value); },
+                };
+
+                if field.attr.is_skippable() {
+                    quote! {
+                        if let ::core::option::Option::Some(#value) = &#this {
+                            #write
+                        }
+                    }
+                } else {
+                    quote! {
+                        let #value = &#this;
+                        #write
                     }
-                    AsyncDebugFieldIdent::Index(_) => quote! { #vis #generic_argument, },
                 }
             })
             .collect()
     }
 
-    fn to_token_stream_impl_ident_body(&self, prefix: Option<TokenStream>) -> Result<TokenStream> {
+    /// Every field of the generated struct/enum, with `propagate` those of `try_async_debug`
+    fn to_token_stream_impl_ident_body(
+        &self,
+        prefix: Option<TokenStream>,
+        propagate: bool,
+    ) -> Result<TokenStream> {
         self.get_fields()
             .values()
             .filter(|field| field.attr.skip.is_none())
-            .map(|field| field.to_token_stream(prefix.clone()))
+            .map(|field| field.to_token_stream(prefix.clone(), propagate))
             .collect()
     }
+
+    /// `Rendered` struct, or tuple, `name` with the `Rendered` value of every field.
+    ///
+    /// With the `non_exhaustive` argument it is followed by `..` if `is_non_exhaustive`, or if
+    /// any field is skipped in the profile of the traversal.
+    fn to_token_stream_impl_ident_body_rendered(
+        &self,
+        prefix: Option<TokenStream>,
+        krate: &Path,
+        name: &str,
+        tuple: bool,
+        non_exhaustive: Option<bool>,
+    ) -> TokenStream {
+        let fields = AsyncDebugField::fields_ident();
+        let (ty, debug, debug_non_exhaustive) = if tuple {
+            (
+                quote! { #krate::Rendered },
+                quote! { debug_tuple },
+                quote! { debug_tuple_non_exhaustive },
+            )
+        } else {
+            (
+                quote! { (&str, #krate::Rendered) },
+                quote! { debug_struct },
+                quote! { debug_struct_non_exhaustive },
+            )
+        };
+
+        let (is_non_exhaustive, skipped_in_profile) = self.rendered_non_exhaustive(non_exhaustive);
+        let statements = self
+            .get_fields()
+            .values()
+            .filter(|field| field.attr.skip.is_none())
+            .map(|field| field.to_token_stream_rendered(prefix.clone(), skipped_in_profile));
+
+        let flag = AsyncDebugField::non_exhaustive_ident();
+        let (declare, rendered) = if is_non_exhaustive {
+            (
+                quote! {},
+                quote! { #krate::Rendered::#debug_non_exhaustive(#name, &#fields) },
+            )
+        } else if skipped_in_profile {
+            (
+                quote! { let mut #flag = false; },
+                quote! {
+                    if #flag {
+                        #krate::Rendered::#debug_non_exhaustive(#name, &#fields)
+                    } else {
+                        #krate::Rendered::#debug(#name, &#fields)
+                    }
+                },
+            )
+        } else {
+            (
+                quote! {},
+                quote! { #krate::Rendered::#debug(#name, &#fields) },
+            )
+        };
+
+        quote! {
+            {
+                #[allow(unused_mut)]
+                let mut #fields: ::std::vec::Vec<#ty> = ::std::vec::Vec::new();
+                #declare
+                #(#statements)*
+                #rendered
+            }
+        }
+    }
+
+    /// Whether the `Rendered` fields are followed by `..`, given the `non_exhaustive` of
+    /// `get_non_exhaustive`, as `is_non_exhaustive`, or only when any field is skipped in the
+    /// profile of the traversal, which the statements of `to_token_stream_rendered` then flag
+    fn rendered_non_exhaustive(&self, non_exhaustive: Option<bool>) -> (bool, bool) {
+        let is_non_exhaustive = self.is_non_exhaustive(non_exhaustive);
+        let skipped_in_profile = non_exhaustive.is_some()
+            && !is_non_exhaustive
+            && self
+                .get_fields()
+                .values()
+                .flat_map(|field| field.profiles())
+                .any(|(_, field)| field.attr.skip.is_some());
+
+        (is_non_exhaustive, skipped_in_profile)
+    }
+
+    /// `RenderedFields` with the `Rendered` value of every field, for `AsyncDebugFlatten`, see
+    /// `to_token_stream_impl_ident_body_rendered`
+    fn to_token_stream_impl_ident_body_flatten(
+        &self,
+        prefix: Option<TokenStream>,
+        krate: &Path,
+        non_exhaustive: Option<bool>,
+    ) -> TokenStream {
+        let fields = AsyncDebugField::fields_ident();
+        let (is_non_exhaustive, skipped_in_profile) = self.rendered_non_exhaustive(non_exhaustive);
+        let statements = self
+            .get_fields()
+            .values()
+            .filter(|field| field.attr.skip.is_none())
+            .map(|field| field.to_token_stream_rendered(prefix.clone(), skipped_in_profile));
+
+        let flag = AsyncDebugField::non_exhaustive_ident();
+        let (declare, non_exhaustive) = if skipped_in_profile {
+            (quote! { let mut #flag = false; }, quote! { #flag })
+        } else {
+            (quote! {}, quote! { #is_non_exhaustive })
+        };
+
+        quote! {
+            {
+                #[allow(unused_mut)]
+                let mut #fields: ::std::vec::Vec<(&'static str, #krate::Rendered)> =
+                    ::std::vec::Vec::new();
+                #declare
+                #(#statements)*
+                #krate::RenderedFields::new(#fields, #non_exhaustive)
+            }
+        }
+    }
+
+    /// Whether any field is `flatten`ed
+    fn has_flattened_fields(&self) -> bool {
+        self.get_fields()
+            .values()
+            .any(|field| field.attr.flatten.is_some())
+    }
+
+    /// `FieldNames` of every field that is rendered, including those of `flatten`ed fields
+    fn to_token_stream_field_names(&self, krate: &Path) -> TokenStream {
+        let fields = self
+            .get_fields()
+            .values()
+            .filter(|field| field.attr.skip.is_none());
+        let (flattened, names): (Vec<_>, Vec<_>) =
+            fields.partition(|field| field.attr.flatten.is_some());
+
+        let names = names.into_iter().map(|field| field.name());
+        let flattened = flattened.into_iter().map(|field| {
+            let ty = &field.field.ty;
+            quote! { &<#ty as #krate::AsyncDebugFlatten>::FIELD_NAMES }
+        });
+
+        quote! {
+            #krate::FieldNames {
+                names: &[#(#names),*],
+                flattened: &[#(#flattened),*],
+            }
+        }
+    }
+
+    /// Constant expressions failing compilation if a `flatten`ed field of the struct `ident` has a
+    /// field named like another one, spanned at the flattened field and naming the clash
+    fn to_token_stream_assert_unique_field_names(
+        &self,
+        ident: &Ident,
+        krate: &Path,
+    ) -> Vec<TokenStream> {
+        let fields = self
+            .get_fields()
+            .values()
+            .filter(|field| field.attr.skip.is_none());
+        let (flattened, fields): (Vec<_>, Vec<_>) =
+            fields.partition(|field| field.attr.flatten.is_some());
+        let field_names = |field: &AsyncDebugField| {
+            let ty = &field.field.ty;
+            quote! { <#ty as #krate::AsyncDebugFlatten>::FIELD_NAMES }
+        };
+
+        let mut assertions = Vec::new();
+
+        for (index, flattened_field) in flattened.iter().enumerate() {
+            let flattened_name = flattened_field.name();
+            let flattened_names = field_names(flattened_field);
+            let span = flattened_field
+                .field
+                .ident
+                .as_ref()
+                .map_or_else(|| flattened_field.field.span(), Ident::span);
+
+            for field in &fields {
+                let name = field.name();
+                let message = format!(
+                    "flattened field `{flattened_name}` of `{ident}` has a field named `{name}`, like another field of `{ident}`",
+                );
+
+                assertions.push(quote_spanned! {span=>
+                    ::core::assert!(!#flattened_names.contains(#name), #message)
+                });
+            }
+
+            for other in &flattened[..index] {
+                let other_name = other.name();
+                let other_names = field_names(other);
+                let message = format!(
+                    "flattened fields `{other_name}` and `{flattened_name}` of `{ident}` have fields named alike",
+                );
+
+                assertions.push(quote_spanned! {span=>
+                    ::core::assert!(!#flattened_names.intersects(&#other_names), #message)
+                });
+            }
+        }
+
+        assertions
+    }
+}
+
+/// Whether `ts` contains any of `idents`, e.g. whether a type refers to a generic parameter
+fn mentions_any(ts: TokenStream, idents: &[&Ident]) -> bool {
+    ts.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => idents.iter().any(|other| ident == **other),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
 }
 
+/// `T` of `Wrapper<T>`, e.g. `Option<T>`, by the last segment of its path, `None` for any other
+/// type
+fn wrapped_ty<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first() {
+        Some(GenericArgument::Type(ty)) if segment.ident == wrapper && args.args.len() == 1 => {
+            Some(ty)
+        }
+        _ => None,
+    }
+}
+
+#[derive(Clone)]
 pub struct AsyncDebugField {
     pub field: Field,
-    pub variant_ident: Option<Ident>,
+    /// Index and ident of the enum variant containing this field
+    pub variant: Option<(usize, Ident)>,
     pub ident: AsyncDebugFieldIdent,
     pub attr: AsyncDebugAttrField,
+    /// Path to the `async_debug` crate
+    pub krate: Path,
+    /// Whether this is a `field(...)` of the struct, whose value is computed from `self`
+    pub computed: bool,
+    /// Whether `Serialize` is derived for the generated struct/enum
+    pub serde: bool,
 }
 
 impl AsyncDebugField {
-    pub fn new(field: Field, variant_ident: Option<Ident>, index: usize) -> Result<Self> {
+    pub fn new(
+        field: Field,
+        variant: Option<(usize, Ident)>,
+        index: usize,
+        container_attr: &AsyncDebugAttrStructEnum,
+    ) -> Result<Self> {
         let ident = field
             .ident
             .clone()
@@ -110,31 +595,286 @@ impl AsyncDebugField {
 
         attr.validate(&field.ident)?;
 
+        if attr.flatten.is_some() && (variant.is_some() || field.ident.is_none()) {
+            return Err(Error::new(
+                field.span(),
+                "flatten can only be used in structs with named fields",
+            ));
+        }
+
+        let mut ty = &field.ty;
+
+        if attr.optional.is_some() {
+            ty = wrapped_ty(ty, "Option").ok_or_else(|| {
+                Error::new(
+                    field.ty.span(),
+                    "optional can only be used on a field of type Option<...>",
+                )
+            })?;
+        }
+
+        if attr.upgrade.is_some() && wrapped_ty(ty, "Weak").is_none() {
+            return Err(Error::new(
+                field.ty.span(),
+                "upgrade can only be used on a field of type Weak<...>, or Option<Weak<...>> with optional",
+            ));
+        }
+
         Ok(Self {
             field,
-            variant_ident,
+            variant,
             ident,
             attr,
+            krate: container_attr.crate_path(),
+            computed: false,
+            serde: container_attr.serde,
         })
     }
 
-    pub fn ty(&self) -> Result<Type> {
-        if let Some(ty) = &self.attr.ty {
-            return Ok(ty.clone());
-        }
+    /// Field of the generated struct for a `field(...)` of the struct itself, declared as a field
+    /// of type `Self` as its value is computed from `self`
+    pub fn computed(
+        computed: &ComputedField,
+        container_attr: &AsyncDebugAttrStructEnum,
+    ) -> Result<Self> {
+        let name = computed
+            .name
+            .clone()
+            .ok_or_else(|| Error::new_call_site("field(...) requires name"))?;
+        let field = Field {
+            attrs: Vec::new(),
+            vis: Visibility::Inherited,
+            ident: Some(name.clone()),
+            colon_token: None,
+            ty: parse_quote! { Self },
+        };
+
+        Ok(Self {
+            field,
+            variant: None,
+            ident: AsyncDebugFieldIdent::Ident(name),
+            attr: computed.attr(),
+            krate: container_attr.crate_path(),
+            computed: true,
+            serde: container_attr.serde,
+        })
+    }
+
+    /// Hygienic binding for the `AsyncDebugContext` of the traversal rendering this field
+    pub fn context_ident() -> Ident {
+        Ident::new("async_debug_context", Span::mixed_site())
+    }
+
+    /// Whether this field is `nested` and refers to `ident`, the struct/enum itself
+    pub fn is_recursive(&self, ident: &Ident) -> bool {
+        self.attr.nested.is_some()
+            && mentions_any(
+                self.field.ty.to_token_stream(),
+                &[ident, &Ident::new("Self", Span::call_site())],
+            )
+    }
+
+    /// Whether this field is rendered through `AsyncDebugTruncate`
+    pub fn is_truncated(&self) -> bool {
+        self.attr.nested.is_none() && self.attr.has_limits()
+    }
+
+    /// Whether this field is rendered within the `AsyncDebugContext` of the traversal
+    pub fn uses_context(&self) -> bool {
+        self.attr.nested.is_some() || self.attr.flatten.is_some() || self.is_truncated()
+    }
+
+    /// Whether the value of this field in the generated struct/enum is evaluated within the
+    /// `AsyncDebugContext` of `async_debug`, a `flatten`ed one is the generated struct of the
+    /// flattened struct
+    pub fn uses_snapshot_context(&self) -> bool {
+        self.uses_context() && self.attr.flatten.is_none()
+    }
+
+    /// `AsyncDebugNested`, `AsyncDebugFlatten` or `AsyncDebugTruncate` bound for a `nested`,
+    /// `flatten`ed or truncated field of a generic type, it is rendered through the field type
+    /// itself unless locked with `async_call` or converted with `map`
+    pub fn context_predicate(&self, type_params: &[&Ident]) -> Option<WherePredicate> {
         let ty = &self.field.ty;
-        parse2(quote! { &#ty })
+        let krate = &self.krate;
+
+        let bound = if self.attr.nested.is_some() {
+            quote! { #krate::AsyncDebugNested }
+        } else if self.attr.flatten.is_some() {
+            quote! { #krate::AsyncDebugFlatten }
+        } else if self.is_truncated() {
+            quote! { #krate::AsyncDebugTruncate }
+        } else {
+            return None;
+        };
+
+        (self.attr.async_call.is_none()
+            && self.attr.map.is_none()
+            && mentions_any(ty.to_token_stream(), type_params))
+        .then(|| parse_quote! { #ty: #bound })
+    }
+
+    /// `Debug` bound for a field of a generic type, required to render the generated struct/enum.
+    ///
+    /// References are peeled, `&T` is `Debug` exactly when `T` is, and bounding the reference
+    /// itself would be ambiguous with a `for<'x> &'x T: Debug` in the where clause.
+    pub fn debug_predicate(&self, type_params: &[&Ident]) -> Option<WherePredicate> {
+        let mut ty = self.attr.ty.as_ref().unwrap_or(&self.field.ty);
+
+        while let Type::Reference(reference) = ty {
+            ty = &reference.elem;
+        }
+
+        (self.attr.skip.is_none()
+            && !self.uses_context()
+            && mentions_any(ty.to_token_stream(), type_params))
+        .then(|| parse_quote! { #ty: ::core::fmt::Debug })
+    }
+
+    /// Bounds required to render this field within a traversal, in any of its profiles, beyond
+    /// the `context_predicate` of the field itself
+    pub fn rendered_predicates(&self, type_params: &[&Ident]) -> Vec<WherePredicate> {
+        let profiles = self
+            .profiles()
+            .filter(|(_, field)| field.attr.skip.is_none())
+            .map(|(_, field)| field)
+            .collect::<Vec<_>>();
+
+        self.debug_predicate(type_params)
+            .into_iter()
+            .chain(profiles.iter().flat_map(|field| {
+                field
+                    .context_predicate(type_params)
+                    .into_iter()
+                    .chain(field.debug_predicate(type_params))
+            }))
+            .collect()
+    }
+
+    /// Type of the value `async_call` is awaited on, that within the `Option` of an `optional`
+    /// field, the target of the `Weak` of an `upgrade` one
+    fn value_ty(&self) -> &Type {
+        let mut ty = &self.field.ty;
+
+        if self.attr.optional.is_some() {
+            ty = wrapped_ty(ty, "Option").unwrap_or(ty);
+        }
+
+        if self.attr.upgrade.is_some() {
+            ty = wrapped_ty(ty, "Weak").unwrap_or(ty);
+        }
+
+        ty
+    }
+
+    /// Type of this field in the generated struct/enum, a `try` one holds a `Result` of the value
+    /// and its rendered error, unless the error is propagated by `try_async_debug`. A `nested` one
+    /// is `Rendered` as a whole, also within an `each(...)`, so it can be elided past the maximum
+    /// depth.
+    pub fn ty(&self, lifetime: Option<&Lifetime>, propagate: bool) -> Result<Type> {
+        let krate = &self.krate;
+
+        if self.attr.flatten.is_some() {
+            let ty = &self.field.ty;
+            let lifetime = lifetime.map_or_else(|| quote! { '_ }, ToTokens::to_token_stream);
+
+            return parse2(quote! { <#ty as #krate::AsyncDebugFlatten>::Snapshot<#lifetime> });
+        }
+
+        let mut ty = if self.uses_context() {
+            parse2(quote! { #krate::Rendered })?
+        } else if let Some(ty) = &self.attr.ty {
+            ty.clone()
+        } else if self.attr.to_string.is_some() {
+            parse2(quote! { ::std::string::String })?
+        } else if let Some(into) = &self.attr.into {
+            into.clone()
+        } else if self.attr.to_owned.is_some() {
+            let ty = self.value_ty();
+            parse2(quote! { <#ty as ::std::borrow::ToOwned>::Owned })?
+        } else {
+            let ty = self.value_ty();
+            parse2(quote! { &#lifetime #ty })?
+        };
+
+        if self.attr.r#try.is_some() && !propagate && !self.uses_context() {
+            ty = parse2(quote! { ::core::result::Result<#ty, #krate::Rendered> })?;
+        }
+
+        if self.attr.nested.is_none() {
+            for _ in 0..self.attr.each_depth() {
+                ty = parse2(quote! { #krate::Each<#ty> })?;
+            }
+        }
+
+        if self.attr.upgrade.is_some() && !self.uses_context() {
+            ty = parse2(quote! { #krate::Upgraded<#ty> })?;
+        }
+
+        if self.attr.optional.is_some() && !self.uses_context() {
+            ty = parse2(quote! { ::core::option::Option<#ty> })?;
+        }
+
+        Ok(ty)
     }
 
     pub fn custom_type(&self) -> bool {
         self.attr.ty.is_some()
+            || self.attr.to_owned.is_some()
+            || self.attr.to_string.is_some()
+            || self.attr.into.is_some()
+            || self.uses_context()
     }
 
+    /// Generic parameter of the generated struct/enum for this field.
+    ///
+    /// Within an enum the variant index precedes the variant ident, it is terminated by the first
+    /// underscore so fields of different variants can not collide, e.g. variant `A_b` field `c`
+    /// and variant `A` field `b_c`.
+    ///
+    /// It is spanned at the derive rather than the variant, so `non_camel_case_types` is not
+    /// reported for it within impls derived for the generated struct/enum, e.g. `Serialize`.
     pub fn generic_argument_ident(&self) -> Ident {
-        if let Some(variant_ident) = &self.variant_ident {
-            format_ident!("T_AsyncDebug_{}_{}", variant_ident, self.ident)
+        if let Some((variant_index, variant_ident)) = &self.variant {
+            format_ident!(
+                "T_AsyncDebug_{}_{}_{}",
+                variant_index,
+                variant_ident,
+                self.ident,
+                span = Span::call_site(),
+            )
+        } else {
+            format_ident!("T_AsyncDebug_{}", self.ident, span = Span::call_site())
+        }
+    }
+
+    /// Hygienic binding for this field in the match arm of an enum variant
+    pub fn binding_ident(&self) -> Ident {
+        format_ident!("self_{}", self.ident, span = Span::mixed_site())
+    }
+
+    /// Pattern binding this field in the match arm of an enum variant, skipped fields are ignored
+    pub fn to_token_stream_pattern(&self) -> Option<TokenStream> {
+        let binding = self.binding_ident();
+        let skip = self.attr.skip.is_some();
+
+        match &self.ident {
+            AsyncDebugFieldIdent::Ident(_) if skip => None,
+            AsyncDebugFieldIdent::Ident(ident) => Some(quote! { #ident: #binding }),
+            AsyncDebugFieldIdent::Index(_) if skip => Some(quote! { _ }),
+            AsyncDebugFieldIdent::Index(_) => Some(quote! { #binding }),
+        }
+    }
+
+    /// Type of this field in the generated struct/enum, in terms of its generic parameter, which
+    /// is within an `Option` when it may be skipped by `skip_if`
+    pub fn to_token_stream_generated_ty(&self) -> TokenStream {
+        let generic_argument = self.generic_argument_ident();
+
+        if self.attr.is_skippable() {
+            quote! { ::core::option::Option<#generic_argument> }
         } else {
-            format_ident!("T_AsyncDebug_{}", self.ident)
+            generic_argument.to_token_stream()
         }
     }
 
@@ -142,41 +882,771 @@ impl AsyncDebugField {
         parse2(self.generic_argument_ident().to_token_stream())
     }
 
-    pub fn to_token_stream(&self, prefix: Option<TokenStream>) -> Result<TokenStream> {
+    /// Hygienic binding for the `DebugStruct` or `DebugTuple` of a generated `Debug` impl
+    pub fn debug_ident() -> Ident {
+        Ident::new("debug", Span::mixed_site())
+    }
+
+    /// Hygienic binding for whether a field was skipped in the profile of the traversal, see
+    /// `to_token_stream_rendered`
+    pub fn non_exhaustive_ident() -> Ident {
+        Ident::new("non_exhaustive", Span::mixed_site())
+    }
+
+    /// Hygienic binding for the fields rendered so far, see `to_token_stream_rendered`
+    pub fn fields_ident() -> Ident {
+        Ident::new("fields", Span::mixed_site())
+    }
+
+    /// Name of this field as rendered and redacted, its index in a tuple struct or variant
+    fn name(&self) -> String {
+        match &self.ident {
+            AsyncDebugFieldIdent::Ident(ident) => ident.unraw().to_string(),
+            AsyncDebugFieldIdent::Index(index) => index.index.to_string(),
+        }
+    }
+
+    /// This field as rendered in each profile it has a `profile(name, ...)` attribute for
+    pub fn profiles(&self) -> impl Iterator<Item = (&Ident, Self)> + '_ {
+        self.attr.profile.iter().map(|(name, profile)| {
+            (
+                name,
+                Self {
+                    attr: self.attr.merge(profile),
+                    ..self.clone()
+                },
+            )
+        })
+    }
+
+    /// This field of `self`, or its binding in the match arm of an enum variant, `self` itself
+    /// for a computed field
+    pub fn to_token_stream_self(&self, prefix: Option<TokenStream>) -> TokenStream {
         let ident = &self.ident;
-        let ts_ident = {
-            match ident {
-                AsyncDebugFieldIdent::Ident(_) => ident.clone(),
-                AsyncDebugFieldIdent::Index(index) => {
-                    if self.variant_ident.is_some() {
-                        AsyncDebugFieldIdent::Ident(format_ident!("self_{}", index.index))
+
+        if self.computed {
+            quote! { (*self) }
+        } else if self.variant.is_some() {
+            self.binding_ident().to_token_stream()
+        } else {
+            quote! { #prefix #ident }
+        }
+    }
+
+    /// `ident: value,` for this field of the generated struct/enum, with `propagate` returning
+    /// early with the error of a `try` field
+    pub fn to_token_stream(
+        &self,
+        prefix: Option<TokenStream>,
+        propagate: bool,
+    ) -> Result<TokenStream> {
+        let ts = self.to_token_stream_value(self.to_token_stream_self(prefix), propagate);
+
+        Ok(match &self.ident {
+            AsyncDebugFieldIdent::Ident(ident) => quote! { #ident: #ts, },
+            AsyncDebugFieldIdent::Index(_) => quote! { #ts, },
+        })
+    }
+
+    /// Statement pushing the `Rendered` value of this field onto `fields`, with the arguments of
+    /// the profile of the traversal, unless skipped in that profile or by `skip_if`.
+    ///
+    /// With `non_exhaustive` skipping it in a profile sets `non_exhaustive`, so the struct/enum is
+    /// rendered followed by `..`.
+    pub fn to_token_stream_rendered(
+        &self,
+        prefix: Option<TokenStream>,
+        non_exhaustive: bool,
+    ) -> TokenStream {
+        let this = self.to_token_stream_self(prefix);
+
+        let push = |field: &Self| {
+            if field.attr.skip.is_some() {
+                let flag = Self::non_exhaustive_ident();
+
+                return if non_exhaustive {
+                    quote! { #flag = true; }
+                } else {
+                    quote! {}
+                };
+            }
+
+            let fields = Self::fields_ident();
+            let rendered = Ident::new("rendered", Span::mixed_site());
+            let ts = field.to_token_stream_rendered_value(this.clone());
+
+            let push = match &field.ident {
+                AsyncDebugFieldIdent::Ident(_) if field.attr.flatten.is_some() => {
+                    quote! { #fields.extend(#rendered); }
+                }
+                AsyncDebugFieldIdent::Ident(_) => {
+                    let name = field.name();
+                    quote! { #fields.push((#name, #rendered)); }
+                }
+                AsyncDebugFieldIdent::Index(_) => quote! { #fields.push(#rendered); },
+            };
+
+            if field.attr.is_skippable() {
+                quote! {
+                    if let ::core::option::Option::Some(#rendered) = #ts {
+                        #push
+                    }
+                }
+            } else {
+                quote! {
+                    let #rendered = #ts;
+                    #push
+                }
+            }
+        };
+
+        self.to_token_stream_per_profile(push)
+    }
+
+    /// `Rendered` value of this field, with the arguments of the profile of the traversal, as that
+    /// of the `transparent` struct containing it
+    pub fn to_token_stream_rendered_transparent(&self, prefix: Option<TokenStream>) -> TokenStream {
+        let this = self.to_token_stream_self(prefix);
+
+        self.to_token_stream_per_profile(|field| field.to_token_stream_rendered_value(this.clone()))
+    }
+
+    /// `ts(field)` for this field as rendered in the profile of the traversal
+    fn to_token_stream_per_profile(&self, ts: impl Fn(&Self) -> TokenStream) -> TokenStream {
+        let context = Self::context_ident();
+
+        if self.attr.profile.is_empty() {
+            return ts(self);
+        }
+
+        let arms = self.profiles().map(|(name, field)| {
+            let name = name.unraw().to_string();
+            let ts = ts(&field);
+
+            quote! {
+                ::core::option::Option::Some(#name) => { #ts }
+            }
+        });
+        let ts = ts(self);
+
+        quote! {
+            match #context.profile() {
+                #(#arms)*
+                _ => { #ts }
+            }
+        }
+    }
+
+    /// Value of this field in the generated struct/enum, `None` when skipped by `skip_if`
+    fn to_token_stream_value(&self, ts: TokenStream, propagate: bool) -> TokenStream {
+        let nested_context = Ident::new("nested_context", Span::mixed_site());
+
+        let render = |mut value: TokenStream| {
+            if let Some(map) = &self.attr.map {
+                value = quote! { #map(&#value) };
+            }
+
+            if self.attr.nested.is_some() {
+                value = quote! { #value.async_debug_nested(&#nested_context).await };
+            } else if self.attr.flatten.is_some() {
+                let krate = &self.krate;
+                value = quote! {
+                    #krate::AsyncDebugFlatten::async_debug_flatten_snapshot(&#value).await
+                };
+            } else if self.is_truncated() {
+                value = self.to_token_stream_truncated(value);
+            }
+
+            if self.attr.copy.is_some() {
+                value = quote! { *#value };
+            } else if self.attr.clone.is_some() {
+                value = quote! { #value.clone() }
+            } else if self.attr.to_owned.is_some() {
+                value = quote! { #value.to_owned() }
+            } else if self.attr.to_string.is_some() {
+                value = quote! { #value.to_string() }
+            } else if let Some(into) = &self.attr.into {
+                value = quote! { ::core::convert::Into::<#into>::into(#value.to_owned()) }
+            }
+
+            if !self.custom_type() {
+                value = quote! { &#value };
+            }
+
+            value
+        };
+
+        let ts = self.to_token_stream_optional(ts, self.uses_context(), |ts| {
+            self.to_token_stream_upgrade(ts, self.uses_context(), |ts| {
+                self.to_token_stream_async_call(
+                    ts,
+                    &self.attr.async_call_steps(),
+                    &|value| {
+                        self.to_token_stream_try(value, self.uses_context(), propagate, |value| {
+                            self.to_token_stream_skip_if(value, render)
+                        })
+                    },
+                    &|cycle| self.to_token_stream_present(cycle),
+                )
+            })
+        });
+
+        self.to_token_stream_render_within(ts)
+    }
+
+    /// `leaf(value)` of `ts` after awaiting each step of an `async_call` pipeline on a reference
+    /// to it in turn, the values are kept alive until `leaf` has been evaluated. For a `nested`
+    /// field it is `err(cycle)` instead when a step would await a value already being rendered,
+    /// see `to_token_stream_async_call_target`.
+    ///
+    /// A single call is awaited in place, unless `leaf` refers to the value more than once with
+    /// `skip_if`, or passes it to `map`, whose argument type would otherwise be expected of the
+    /// awaited value rather than coerced to.
+    fn to_token_stream_async_call(
+        &self,
+        mut ts: TokenStream,
+        steps: &[AsyncCallStep],
+        leaf: &dyn Fn(TokenStream) -> TokenStream,
+        err: &dyn Fn(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        let value = Ident::new("value", Span::mixed_site());
+        let mut statements = Vec::new();
+
+        for (index, step) in steps.iter().enumerate() {
+            match step {
+                AsyncCallStep::Call(async_call) if self.attr.nested.is_some() => {
+                    let ok = self.to_token_stream_async_call(
+                        value.to_token_stream(),
+                        &steps[index + 1..],
+                        leaf,
+                        err,
+                    );
+                    let call = self.to_token_stream_async_call_target(
+                        ts,
+                        async_call,
+                        |target| {
+                            quote! {
+                                {
+                                    let #value = #async_call(#target).await;
+                                    #ok
+                                }
+                            }
+                        },
+                        err,
+                    );
+
+                    if statements.is_empty() {
+                        return call;
+                    }
+
+                    return quote! {
+                        {
+                            #(#statements)*
+                            #call
+                        }
+                    };
+                }
+                AsyncCallStep::Call(async_call)
+                    if steps.len() == 1 && !self.attr.is_skippable() && self.attr.map.is_none() =>
+                {
+                    return leaf(quote! { #async_call(&#ts).await });
+                }
+                AsyncCallStep::Call(async_call) => {
+                    statements.push(quote! { let #value = #async_call(&#ts).await; });
+                    ts = value.to_token_stream();
+                }
+                AsyncCallStep::Each(steps) => {
+                    let item = self.to_token_stream_async_call(
+                        value.to_token_stream(),
+                        steps,
+                        leaf,
+                        &|cycle| cycle,
+                    );
+                    let each = self.to_token_stream_each(ts, item, self.attr.nested.is_some());
+
+                    return quote! {
+                        {
+                            #(#statements)*
+                            #each
+                        }
+                    };
+                }
+            }
+        }
+
+        let ts = leaf(ts);
+
+        if statements.is_empty() {
+            ts
+        } else {
+            quote! {
+                {
+                    #(#statements)*
+                    #ts
+                }
+            }
+        }
+    }
+
+    /// `Each` of `item`, which renders the binding `value`, for every item of `ts` through
+    /// `AsyncDebugEach`, itself rendered with `rendered`
+    fn to_token_stream_each(
+        &self,
+        ts: TokenStream,
+        item: TokenStream,
+        rendered: bool,
+    ) -> TokenStream {
+        let krate = &self.krate;
+        let value = Ident::new("value", Span::mixed_site());
+        let keys = Ident::new("keys", Span::mixed_site());
+        let items = Ident::new("items", Span::mixed_site());
+        let each = Ident::new("each", Span::mixed_site());
+
+        let mut ts_each = quote! { #krate::Each::from_parts(#keys, #each) };
+
+        if rendered {
+            ts_each = quote! { #krate::Rendered::each(&#ts_each) };
+        }
+
+        quote! {
+            {
+                use #krate::AsyncDebugEach as _;
+
+                let (#keys, #items) = #ts.async_debug_each().into_parts();
+                let mut #each = ::std::vec::Vec::with_capacity(#items.len());
+
+                for #value in #items {
+                    #each.push(#item);
+                }
+
+                #ts_each
+            }
+        }
+    }
+
+    /// `Rendered` value of this field, for `async_debug_with()` and nested traversals, `None` when
+    /// skipped by `skip_if`.
+    ///
+    /// The `async_call` is awaited through `AsyncDebugContext::call`, which renders `<locked>` or
+    /// `<timed out>` instead of waiting as the options of the traversal require. Redacted fields
+    /// and those past the maximum depth are rendered without calling it.
+    fn to_token_stream_rendered_value(&self, ts: TokenStream) -> TokenStream {
+        let krate = &self.krate;
+        let context = Self::context_ident();
+        let nested_context = Ident::new("nested_context", Span::mixed_site());
+
+        let render = |mut value: TokenStream| {
+            if let Some(map) = &self.attr.map {
+                value = quote! { #map(&#value) };
+            }
+
+            if self.attr.nested.is_some() {
+                quote! { #value.async_debug_nested(&#nested_context).await }
+            } else if self.attr.flatten.is_some() {
+                quote! { #value.async_debug_flatten(&#context).await }
+            } else if self.is_truncated() {
+                self.to_token_stream_truncated(value)
+            } else if self.attr.copy.is_some() {
+                self.to_token_stream_leaf(quote! { &*#value })
+            } else if self.attr.clone.is_some() {
+                self.to_token_stream_leaf(quote! { &#value.clone() })
+            } else if self.attr.to_owned.is_some() {
+                self.to_token_stream_leaf(quote! { &#value.to_owned() })
+            } else if self.attr.to_string.is_some() {
+                self.to_token_stream_leaf(quote! { &#value.to_string() })
+            } else if let Some(into) = &self.attr.into {
+                self.to_token_stream_leaf(
+                    quote! { &::core::convert::Into::<#into>::into(#value.to_owned()) },
+                )
+            } else {
+                self.to_token_stream_leaf(quote! { &#value })
+            }
+        };
+
+        let ts = self.to_token_stream_optional(ts, true, |ts| {
+            self.to_token_stream_upgrade(ts, true, |ts| {
+                self.to_token_stream_async_call_rendered(
+                    ts,
+                    &self.attr.async_call_steps(),
+                    &|value| {
+                        self.to_token_stream_try(value, true, false, |value| {
+                            self.to_token_stream_skip_if(value, render)
+                        })
+                    },
+                    &|rendered| self.to_token_stream_present(rendered),
+                )
+            })
+        });
+
+        let ts = self.to_token_stream_render_within(ts);
+
+        let name = self.name();
+        let redacted = if self.attr.flatten.is_some() {
+            quote! { #krate::RenderedFields::redacted(#name) }
+        } else {
+            self.to_token_stream_present(quote! { #krate::Rendered::redacted() })
+        };
+
+        quote! {
+            if #context.is_redacted(#name) {
+                #redacted
+            } else {
+                #ts
+            }
+        }
+    }
+
+    /// `leaf(value)` of `ts` after awaiting each step of an `async_call` pipeline through
+    /// `AsyncDebugContext::call`, or `err(rendered)` of the first one that does not complete.
+    ///
+    /// Within an `each(...)` a step that does not complete renders just that item.
+    fn to_token_stream_async_call_rendered(
+        &self,
+        ts: TokenStream,
+        steps: &[AsyncCallStep],
+        leaf: &dyn Fn(TokenStream) -> TokenStream,
+        err: &dyn Fn(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        let context = Self::context_ident();
+        let value = Ident::new("value", Span::mixed_site());
+        let rendered = Ident::new("rendered", Span::mixed_site());
+
+        let Some((step, steps)) = steps.split_first() else {
+            return leaf(ts);
+        };
+
+        match step {
+            AsyncCallStep::Call(async_call) => {
+                let ok = self.to_token_stream_async_call_rendered(
+                    value.to_token_stream(),
+                    steps,
+                    leaf,
+                    err,
+                );
+                let ts_err = err(rendered.to_token_stream());
+
+                self.to_token_stream_async_call_target(
+                    ts,
+                    async_call,
+                    |target| {
+                        quote! {
+                            match #context.call(#async_call(#target)).await {
+                                ::core::result::Result::Ok(#value) => #ok,
+                                ::core::result::Result::Err(#rendered) => #ts_err,
+                            }
+                        }
+                    },
+                    err,
+                )
+            }
+            AsyncCallStep::Each(steps) => {
+                let item = self.to_token_stream_async_call_rendered(
+                    value.to_token_stream(),
+                    steps,
+                    leaf,
+                    &|rendered| rendered,
+                );
+
+                self.to_token_stream_each(ts, item, true)
+            }
+        }
+    }
+
+    /// `call(target)` of a reference to `ts`, which `async_call` is awaited on.
+    ///
+    /// For a `nested` field `target` is the value `async_call` is applied to, after any deref
+    /// coercion, e.g. the `Mutex` within an `Arc<Mutex<_>>`. It is entered into the context the
+    /// field is rendered in before the call, so a value rendered further down that awaits it again,
+    /// e.g. locking the same `Mutex` through an `Arc` back-reference, renders `err(cycle)` instead
+    /// of deadlocking.
+    fn to_token_stream_async_call_target(
+        &self,
+        ts: TokenStream,
+        async_call: &Expr,
+        call: impl FnOnce(TokenStream) -> TokenStream,
+        err: &dyn Fn(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        if self.attr.nested.is_none() {
+            return call(quote! { &#ts });
+        }
+
+        let krate = &self.krate;
+        let nested_context = Ident::new("nested_context", Span::mixed_site());
+        let target = Ident::new("target", Span::mixed_site());
+        let cycle = Ident::new("cycle", Span::mixed_site());
+        let call = call(target.to_token_stream());
+        let err = err(cycle.to_token_stream());
+
+        quote! {
+            {
+                let #target = #krate::async_call_target(#async_call, &#ts);
+
+                match #nested_context.enter(#target) {
+                    ::core::result::Result::Ok(#nested_context) => #call,
+                    ::core::result::Result::Err(#cycle) => #err,
+                }
+            }
+        }
+    }
+
+    /// `some(value)` of the value within the `Option` of an `optional` field, `None` kept as is,
+    /// both `Rendered` like an `Option` with `rendered`. Just `some(ts)` for any other field.
+    fn to_token_stream_optional(
+        &self,
+        ts: TokenStream,
+        rendered: bool,
+        some: impl Fn(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        if self.attr.optional.is_none() {
+            return some(ts);
+        }
+
+        let krate = &self.krate;
+        let value = Ident::new("some", Span::mixed_site());
+        let ts_some = some(quote! { (*#value) });
+
+        let (ts_some, ts_none) = if rendered {
+            (
+                quote! { #krate::Rendered::some(#ts_some) },
+                quote! { #krate::Rendered::none() },
+            )
+        } else {
+            (
+                quote! { ::core::option::Option::Some(#ts_some) },
+                quote! { ::core::option::Option::None },
+            )
+        };
+
+        quote! {
+            match &#ts {
+                ::core::option::Option::Some(#value) => #ts_some,
+                ::core::option::Option::None => #ts_none,
+            }
+        }
+    }
+
+    /// `alive(value)` of the target of the `Weak` of an `upgrade` field, upgraded through
+    /// `AsyncDebugUpgrade`, as an `Upgraded`, or `Rendered` with `rendered`. Just `alive(ts)` for
+    /// any other field.
+    fn to_token_stream_upgrade(
+        &self,
+        ts: TokenStream,
+        rendered: bool,
+        alive: impl Fn(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        if self.attr.upgrade.is_none() {
+            return alive(ts);
+        }
+
+        let krate = &self.krate;
+        let value = Ident::new("strong", Span::mixed_site());
+        let ts_alive = alive(quote! { (*#value) });
+
+        let (ts_alive, ts_dropped) = if rendered {
+            (ts_alive, quote! { #krate::Rendered::dropped() })
+        } else {
+            (
+                quote! { #krate::Upgraded::Alive(#ts_alive) },
+                quote! { #krate::Upgraded::Dropped },
+            )
+        };
+
+        quote! {
+            match #krate::AsyncDebugUpgrade::async_debug_upgrade(&#ts) {
+                ::core::option::Option::Some(#value) => #ts_alive,
+                ::core::option::Option::None => #ts_dropped,
+            }
+        }
+    }
+
+    /// `ok(value)` of the `Ok` value of the result of the `async_call` of a `try` field, as a
+    /// `Result` with the rendered error, or `Rendered` like one with `rendered`. With `propagate`
+    /// the error is instead returned, converted into the `error = ...` type of `try_async_debug`.
+    /// Just `ok(ts)` for any other field.
+    fn to_token_stream_try(
+        &self,
+        ts: TokenStream,
+        rendered: bool,
+        propagate: bool,
+        ok: impl Fn(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        if self.attr.r#try.is_none() {
+            return ok(ts);
+        }
+
+        let krate = &self.krate;
+        let value = Ident::new("ok", Span::mixed_site());
+        let error = Ident::new("error", Span::mixed_site());
+        let ts_ok = ok(value.to_token_stream());
+
+        let (ts_ok, ts_err) = if propagate {
+            (
+                ts_ok,
+                quote! {
+                    return ::core::result::Result::Err(::core::convert::From::from(#error))
+                },
+            )
+        } else if rendered {
+            let ts_error = self.to_token_stream_leaf(quote! { &#error });
+            (
+                quote! { #krate::Rendered::ok(#ts_ok) },
+                quote! { #krate::Rendered::err(#ts_error) },
+            )
+        } else {
+            let ts_error = self.to_token_stream_leaf(quote! { &#error });
+            (
+                quote! { ::core::result::Result::Ok(#ts_ok) },
+                quote! { ::core::result::Result::Err(#ts_error) },
+            )
+        };
+
+        quote! {
+            match #ts {
+                ::core::result::Result::Ok(#value) => #ts_ok,
+                ::core::result::Result::Err(#error) => #ts_err,
+            }
+        }
+    }
+
+    /// `ts` in the scope rendering a nested or truncated field expects, a nested field past the
+    /// maximum depth is elided before its `async_call`
+    fn to_token_stream_render_within(&self, ts: TokenStream) -> TokenStream {
+        let krate = &self.krate;
+
+        if self.attr.nested.is_some() {
+            let context = Self::context_ident();
+            let nested_context = Ident::new("nested_context", Span::mixed_site());
+            let limits = self.attr.to_token_stream_limits();
+            let elided = self.to_token_stream_present(quote! { #krate::Rendered::elided() });
+
+            quote! {
+                {
+                    use #krate::AsyncDebugNested as _;
+
+                    let #nested_context = #context.nested() #limits;
+
+                    if #nested_context.is_past_max_depth() {
+                        #elided
                     } else {
-                        ident.clone()
+                        #ts
                     }
                 }
             }
+        } else if self.attr.flatten.is_some() {
+            quote! {
+                {
+                    use #krate::AsyncDebugFlatten as _;
+
+                    #ts
+                }
+            }
+        } else if self.is_truncated() {
+            quote! {
+                {
+                    use #krate::AsyncDebugTruncate as _;
+
+                    #ts
+                }
+            }
+        } else {
+            ts
+        }
+    }
+
+    /// `render(value)`, or with `skip_if` `None` if it is true of `value`, after any `async_call`
+    fn to_token_stream_skip_if(
+        &self,
+        value: TokenStream,
+        render: impl Fn(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        let skip = if let Some(skip_if) = &self.attr.skip_if {
+            quote! { #skip_if(&#value) }
+        } else if self.attr.skip_if_empty.is_some() {
+            quote! { #value.is_empty() }
+        } else {
+            return render(value);
         };
+        let ts = render(value);
 
-        let mut ts = quote! { #prefix #ts_ident };
+        quote! {
+            if #skip {
+                ::core::option::Option::None
+            } else {
+                ::core::option::Option::Some(#ts)
+            }
+        }
+    }
 
-        if let Some(async_call) = &self.attr.async_call {
-            ts = quote! { #async_call(&#ts).await };
+    /// `ts` as the value of a field that may be skipped by `skip_if`
+    fn to_token_stream_present(&self, ts: TokenStream) -> TokenStream {
+        if self.attr.is_skippable() {
+            quote! { ::core::option::Option::Some(#ts) }
+        } else {
+            ts
         }
+    }
+
+    /// `Rendered` of the reference `value` with its `Debug` implementation. With the `serde`
+    /// feature it serializes with its `Serialize` implementation, or that of the value it derefs
+    /// to, where one is known to the derive, e.g. not for a value of a generic type.
+    fn to_token_stream_leaf(&self, value: TokenStream) -> TokenStream {
+        let krate = &self.krate;
 
-        if self.attr.copy.is_some() {
-            ts = quote! { *#ts };
-        } else if self.attr.clone.is_some() {
-            ts = quote! { #ts.clone() }
+        if !self.serde {
+            return quote! { #krate::Rendered::new(#value) };
         }
 
-        if !self.custom_type() {
-            ts = quote! { &#ts };
+        quote! {
+            {
+                use #krate::{SerializedDebug as _, SerializedDeref as _, SerializedValue as _};
+
+                (&&&#krate::Serialized(#value)).async_debug_serialized()
+            }
         }
+    }
 
-        Ok(match ident {
-            AsyncDebugFieldIdent::Ident(ident) => quote! { #ident: #ts, },
-            AsyncDebugFieldIdent::Index(_) => quote! { #ts, },
-        })
+    /// `Rendered` of `value` through `AsyncDebugTruncate`. With the `serde` feature the items it
+    /// keeps serialize like `to_token_stream_leaf`, where their `Serialize` implementation is
+    /// known to the derive.
+    fn to_token_stream_truncated(&self, value: TokenStream) -> TokenStream {
+        let krate = &self.krate;
+        let context = self.to_token_stream_truncate_context();
+
+        if !self.serde {
+            return quote! { #value.async_debug_truncated(#context) };
+        }
+
+        let truncated = Ident::new("truncated", Span::mixed_site());
+        let truncate_context = Ident::new("truncate_context", Span::mixed_site());
+
+        quote! {
+            {
+                use #krate::{
+                    SerializedTruncatedDebug as _, SerializedTruncatedDeref as _,
+                    SerializedTruncatedValue as _,
+                };
+
+                let #truncated = &#value;
+                let #truncate_context = #context;
+
+                (&&&#krate::SerializedTruncated(#truncated)).async_debug_truncated_serialized(
+                    #truncated.async_debug_truncated(#truncate_context),
+                    #truncate_context,
+                )
+            }
+        }
+    }
+
+    /// `AsyncDebugContext` a truncated field is rendered in, with its own limits applied
+    fn to_token_stream_truncate_context(&self) -> TokenStream {
+        let context = Self::context_ident();
+        let limits = self.attr.to_token_stream_limits();
+
+        if limits.is_empty() {
+            quote! { &#context }
+        } else {
+            quote! { &#context.clone() #limits }
+        }
     }
 }
 
@@ -21,30 +21,81 @@ use self::{common::prelude::*, enums::AsyncDebugEnum, structs::AsyncDebugStruct}
 #[proc_macro_derive(AsyncDebug, attributes(async_debug))]
 /// `AsyncDebug` proc macro
 ///
+/// This macro generates an `async_debug()` method returning the generated struct/enum and, unless
+/// `disable_derive_debug` is set, an `async_debug_with(&AsyncDebugOptions)` method returning it
+/// rendered with the given timeout, try-lock, limit, redaction and profile options.
+///
 /// This macro will use the `#[async_debug()]` attribute on properties of the struct or enum.
 /// Attribute arguments can include:
 ///   async_call = some_function  - Call this async function to render the value
-///   clone                       - Call `.clone()` on the value (exclusive of copy)
-///   copy                        - Dereference the value to take a copy (exclusive of clone)
-///   ty = SomeType               - Use this type as the type of thie property on the generated `*AsyncDebug` struct/enum.
+///   async_call = [f, each(g)]   - Await these functions in turn, each on the result of the last, `each(...)` awaits its own on every item of a list, set or map, whose snapshot is an `Each` of `ty`
+///   map = some_function         - Call this function on a reference to the value, after any `async_call`, and render what it returns (requires ty, to_string, into, nested or truncate)
+///   clone                       - Call `.clone()` on the value (exclusive of the other conversions)
+///   copy                        - Dereference the value to take a copy (exclusive of the other conversions)
+///   to_owned                    - Call `.to_owned()` on the value (exclusive of the other conversions)
+///   to_string                   - Call `.to_string()` on the value (exclusive of the other conversions and ty)
+///   into = Type                 - Convert an owned copy of the value into `Type` (exclusive of the other conversions and ty)
+///   ty = SomeType               - Use this type as the type of this property on the generated struct/enum in the `async_debug_<name>` snapshot module
+///   nested                      - Render the value, after any `async_call`, through `AsyncDebugNested` (exclusive of conversions and ty)
+///   flatten                     - Render the fields of this struct, through `AsyncDebugFlatten`, as fields of this struct (structs with named fields only, exclusive of all but attr and profile)
+///   optional                    - Apply the other arguments to the value within `Some` of this `Option`, render `None` as is (exclusive of skip_if and skip_if_empty)
+///   upgrade                     - Upgrade this `Weak` and apply the other arguments to its target, render `<dropped>` once it is gone (requires a conversion, ty, nested or truncate, exclusive of skip_if and skip_if_empty)
+///   try                         - Unwrap the `Result` returned by `async_call`, rendering an error as `Err(..)`, or returning it from `try_async_debug()` (requires a conversion, ty, nested or truncate, exclusive of skip_if and skip_if_empty)
+///   truncate                    - Render the value, after any `async_call`, through `AsyncDebugTruncate` (exclusive of conversions and ty)
+///   max_depth = 2               - Render nested fields more than this many levels below this nested one as `..`
+///   max_items = 100             - Render at most this many items of this nested or truncated list, set or map
+///   max_len = 1000              - Render at most this many characters of this nested or truncated string
+///   skip_if = some_function     - Leave the field out when this function returns true for a reference to the value, after any `async_call`
+///   skip_if_empty               - Leave the field out when the value, after any `async_call`, `is_empty()` (exclusive of skip_if)
+///   profile(brief, skip)        - Use these arguments instead when rendered with the `brief` profile, generates `async_debug_brief()`
+///   attr(some_attr)             - Add `#[some_attr]` to this property on the generated struct/enum.
+///
+/// On the struct or enum itself the attribute arguments can include:
+///   disable_derive_debug        - Do not derive `Debug` for the generated struct/enum
+///   non_exhaustive              - Render structs/variants with skipped fields, or declared `#[non_exhaustive]`, followed by `..`
+///   transparent                 - Render a struct with a single field, other than skipped ones, exactly as that field (structs only, exclusive of non_exhaustive)
+///   derive(Clone, PartialEq)    - Additional derives for the generated struct/enum, other than `Debug` unless disable_derive_debug
+///   attr(some_attr)             - Add `#[some_attr]` to the generated struct/enum
+///   snapshot = SomeSnapshot     - Add a `SomeSnapshot<'a, ...>` type alias for the generated struct/enum
+///   snapshot_vis = pub(crate)   - Visibility of the `snapshot` type alias, defaults to that of the struct/enum
+///   module = some_module        - Name of the module containing the generated struct/enum
+///   field_vis = pub(crate)      - Visibility of the fields of the generated struct, defaults to private to the module of the struct (structs only)
+///   getters                     - Generate a method borrowing each field of the generated struct, as visible as the struct (structs with named fields only)
+///   error = SomeError           - Generate `try_async_debug()`, returning the first error of a `try` field converted into `SomeError`
+///   field(name = "x", call = f) - Add a field `x` with the value of `f(&self)`, or of `async_call`, followed by the arguments of a field (structs with named fields only, requires ty, to_string, into, nested or truncate)
+///   crate = "some::path"        - Path to the `async_debug` crate, e.g. when re-exported from a facade crate
+///   bound = "T: Clone"          - Where clause predicates of the generated impls, replacing those inferred from the fields, may be repeated
+///   send                        - Require the future returned by `async_debug()` to be `Send`, asserting every field type is `Sync`
+///   local                       - The future returned by `async_debug()` need not be `Send`, boxed futures use `LocalBoxFuture` (exclusive of send)
+///   recursive                   - Box the future rendering this struct/enum as a nested field, implied when a nested field refers to it
+///   max_depth = 8               - Render nested fields deeper than this as `..`, when `async_debug()` is called on this struct/enum, unless overridden by `AsyncDebugOptions`
+///   max_items = 100             - Default `max_items` of nested and truncated fields, when `async_debug()` is called on this struct/enum, unless overridden by `AsyncDebugOptions`
+///   max_len = 1000              - Default `max_len` of nested and truncated fields, when `async_debug()` is called on this struct/enum, unless overridden by `AsyncDebugOptions`
+///
+/// On enum variants the attribute arguments can include:
+///   rename = "SomeName"         - Render the variant under this name
+///   skip                        - Render the variant as `Variant(..)`/`Variant { .. }`, leaving its fields out of the generated enum
+///   name_only                   - Render just the name of the variant, leaving its fields out of the generated enum (exclusive of skip)
+///   transparent                 - Render a variant with a single field, other than skipped ones, exactly as that field (exclusive of skip, name_only and rename)
 pub fn async_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    match async_debug_impl(input.into()) {
+    match async_debug_impl(input.into(), cfg!(feature = "serde")) {
         Ok(output) => output.into(),
         Err(err) => err.to_compile_error().into(),
     }
 }
 
-fn async_debug_impl(input: TokenStream) -> Result<TokenStream> {
+/// `serde` derives `Serialize` for the generated struct/enum, as with the `serde` feature
+fn async_debug_impl(input: TokenStream, serde: bool) -> Result<TokenStream> {
     let input: DeriveInput = parse2(input)?;
 
     match &input.data {
         Data::Struct(DataStruct { fields, .. }) => {
-            AsyncDebugStruct::new(&input, fields)?.to_token_stream()
+            AsyncDebugStruct::new(&input, fields, serde)?.to_token_stream()
         }
         Data::Enum(DataEnum { variants, .. }) => {
             let variants = variants.iter().cloned().collect();
 
-            AsyncDebugEnum::new(&input, variants)?.to_token_stream()
+            AsyncDebugEnum::new(&input, variants, serde)?.to_token_stream()
         }
         Data::Union(..) => Err(Error::new(Span::call_site(), "unions are not supported")),
     }
@@ -14,17 +14,18 @@ pub enum AsyncDebugStruct<'a> {
 }
 
 impl<'a> AsyncDebugStruct<'a> {
-    pub fn new(input: &'a DeriveInput, fields: &Fields) -> Result<Self> {
+    pub fn new(input: &'a DeriveInput, fields: &Fields, serde: bool) -> Result<Self> {
         Ok(match fields {
-            Fields::Named(FieldsNamed { named: fields, .. }) => {
-                Self::Named(AsyncDebugStructNamed::new(input, fields.iter().collect())?)
-            }
+            Fields::Named(FieldsNamed { named: fields, .. }) => Self::Named(
+                AsyncDebugStructNamed::new(input, fields.iter().collect(), serde)?,
+            ),
             Fields::Unit => Self::Unit,
             Fields::Unnamed(FieldsUnnamed {
                 unnamed: fields, ..
             }) => Self::Unnamed(AsyncDebugStructUnnamed::new(
                 input,
                 fields.iter().collect(),
+                serde,
             )?),
         })
     }
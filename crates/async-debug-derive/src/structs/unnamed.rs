@@ -1,6 +1,6 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{DeriveInput, Field, ImplGenerics, TypeGenerics, Visibility, WhereClause};
+use syn::{ext::IdentExt, DeriveInput, Field, Generics, ImplGenerics, TypeGenerics, Visibility};
 
 use crate::{
     common::{attr_struct_enum::AsyncDebugAttrStructEnum, prelude::*, AsyncDebugCommon},
@@ -10,11 +10,13 @@ use crate::{
 pub struct AsyncDebugStructUnnamed<'a> {
     vis: Visibility,
     ident: Ident,
+    generics: &'a Generics,
     generics_impl: ImplGenerics<'a>,
     generics_ty: TypeGenerics<'a>,
-    where_clause: Option<&'a WhereClause>,
     fields: AsyncDebugFieldsMap,
     attr: AsyncDebugAttrStructEnum,
+    /// See `get_non_exhaustive`
+    non_exhaustive: Option<bool>,
 }
 
 impl<'a> AsyncDebugCommon for AsyncDebugStructUnnamed<'a> {}
@@ -26,68 +28,200 @@ impl<'a> AsyncDebugFields for AsyncDebugStructUnnamed<'a> {
 }
 
 impl<'a> AsyncDebugStructUnnamed<'a> {
-    pub fn new(input: &'a DeriveInput, fields: Vec<&Field>) -> Result<Self> {
-        let (generics_impl, generics_ty, where_clause) = input.generics.split_for_impl();
+    pub fn new(input: &'a DeriveInput, fields: Vec<&Field>, serde: bool) -> Result<Self> {
+        let (generics_impl, generics_ty, _) = input.generics.split_for_impl();
 
-        let fields = Self::convert_fields(fields, None)?;
-        let attr = Self::get_attr_struct_enum(&input.attrs)?;
+        let attr = Self::get_attr_struct_enum(&input.attrs, serde)?;
+
+        if !attr.field.is_empty() {
+            return Err(Error::new_call_site(
+                "field(...) can only be used on structs with named fields",
+            ));
+        }
+
+        if attr.getters.is_some() {
+            return Err(Error::new_call_site(
+                "getters is only supported on structs with named fields",
+            ));
+        }
+
+        let fields = Self::convert_fields(fields, None, &attr)?;
+        let non_exhaustive = Self::get_non_exhaustive(&input.attrs, &attr);
 
         Ok(Self {
             vis: input.vis.clone(),
             ident: input.ident.clone(),
+            generics: &input.generics,
             generics_impl,
             generics_ty,
-            where_clause,
             fields,
             attr,
+            non_exhaustive,
         })
     }
 
     pub fn to_token_stream(&self) -> Result<TokenStream> {
-        let (new_generics_names, new_generics) = self.get_new_generics()?;
-        let fields_type = self.get_fields_type();
+        let (new_generics_names, new_generics) = self.get_new_generics(None, false)?;
+        let (_, new_generics_lifetime) =
+            self.get_new_generics(Some(&Self::get_snapshot_lifetime(self.generics)), false)?;
+        let (_, new_generics_try) = self.get_new_generics(None, true)?;
+        let fields_type = self.get_fields_type(Some(&self.attr.to_token_stream_field_vis()));
         let token_stream_impl_ident_body =
-            self.to_token_stream_impl_ident_body(Some(quote! { self. }))?;
+            self.to_token_stream_impl_ident_body(Some(quote! { self. }), false)?;
+        let token_stream_impl_ident_body_try =
+            self.to_token_stream_impl_ident_body(Some(quote! { self. }), true)?;
 
         let vis = &self.vis;
         let ident = &self.ident;
+        let name = ident.unraw().to_string();
 
         let generics_impl = &self.generics_impl;
         let generics_ty = &self.generics_ty;
-        let where_clause = &self.where_clause;
 
-        let async_debug_mod_ident = Self::get_async_debug_mod_ident(ident);
+        let type_params = Self::get_type_params(self.generics);
+        let where_clause = Self::get_where_clause(
+            self.generics,
+            &self.attr,
+            self.fields
+                .values()
+                .filter_map(|field| field.context_predicate(&type_params))
+                .collect(),
+        );
+        let context = self
+            .fields
+            .values()
+            .any(|field| field.uses_snapshot_context());
+        let recursive = self.attr.recursive.is_some()
+            || self.fields.values().any(|field| field.is_recursive(ident));
+
+        let async_debug_mod_ident = Self::get_async_debug_mod_ident(ident, &self.attr);
+
+        let ts_impl_async_debug = Self::to_token_stream_impl_async_debug(
+            &self.attr,
+            vis,
+            ident,
+            self.generics,
+            &where_clause,
+            quote! { #async_debug_mod_ident::#ident <#(#new_generics_lifetime),*> },
+        );
+
+        let output = quote! { #async_debug_mod_ident::#ident <#(#new_generics),*> };
+        let body = quote! {
+            #async_debug_mod_ident::#ident (
+                #token_stream_impl_ident_body
+            )
+        };
+        let output_try = quote! { #async_debug_mod_ident::#ident <#(#new_generics_try),*> };
+        let body_try = quote! {
+            #async_debug_mod_ident::#ident (
+                #token_stream_impl_ident_body_try
+            )
+        };
 
-        let ts_impl_async_debug = quote! {
-            impl #generics_impl AsyncDebug for #ident #generics_ty #where_clause {}
+        let transparent = self.get_transparent_field(self.attr.transparent, "struct")?;
+        let body_rendered = match transparent {
+            Some(field) => field.to_token_stream_rendered_transparent(Some(quote! { self. })),
+            None => self.to_token_stream_impl_ident_body_rendered(
+                Some(quote! { self. }),
+                &self.attr.crate_path(),
+                &name,
+                true,
+                self.non_exhaustive,
+            ),
         };
+        let rendered_predicates = Self::get_rendered_predicates(
+            &self.attr,
+            self.fields
+                .values()
+                .flat_map(|field| field.rendered_predicates(&type_params)),
+        );
+
+        let ts_fn_async_debug = Self::to_token_stream_fn_async_debug(
+            &self.attr,
+            vis,
+            self.get_field_types(),
+            context,
+            output,
+            body,
+        );
+
+        let ts_fn_try_async_debug = Self::to_token_stream_fn_try_async_debug(
+            &self.attr,
+            vis,
+            self.get_field_types(),
+            context,
+            output_try,
+            body_try,
+        );
+
+        let ts_fn_async_debug_with = Self::to_token_stream_fn_async_debug_with(
+            &self.attr,
+            vis,
+            self.generics,
+            Self::extend_where_clause(None, rendered_predicates.clone()),
+            self.get_field_types(),
+        );
+
+        let ts_fn_async_debug_profiles = Self::to_token_stream_fn_async_debug_profiles(
+            &self.attr,
+            vis,
+            Self::extend_where_clause(None, rendered_predicates.clone()),
+            Self::get_profiles(self.fields.values()),
+        );
 
         let ts_impl_ident = quote! {
             #[automatically_derived]
             impl #generics_impl #ident #generics_ty #where_clause {
-                #vis async fn async_debug (&self) -> #async_debug_mod_ident::#ident <#(#new_generics),*>
-                #where_clause
-                {
-                    #async_debug_mod_ident::#ident (
-                        #token_stream_impl_ident_body
-                    )
-                }
+                #ts_fn_async_debug
+                #ts_fn_try_async_debug
+                #ts_fn_async_debug_with
+                #ts_fn_async_debug_profiles
             }
         };
 
-        let derive = {
-            if self.attr.disable_derive_debug.is_none() {
-                Some(quote! { #[derive(Debug)] })
-            } else {
-                None
-            }
+        let ts_impl_async_debug_nested = Self::to_token_stream_impl_async_debug_nested(
+            &self.attr,
+            ident,
+            self.generics,
+            &Self::extend_where_clause(where_clause.clone(), rendered_predicates),
+            recursive,
+            body_rendered,
+        );
+
+        let non_exhaustive = self.is_non_exhaustive(self.non_exhaustive);
+        let custom_debug = self.has_skippable_fields() || non_exhaustive || transparent.is_some();
+        let attrs = self.attr.to_token_stream_attrs(custom_debug);
+        let ts_impl_debug = if let Some(field) = transparent {
+            Self::to_token_stream_impl_debug(
+                &self.attr,
+                ident,
+                &new_generics_names,
+                &None,
+                Self::to_token_stream_debug_transparent(
+                    field.to_token_stream_self(Some(quote! { self. })),
+                ),
+            )
+        } else if custom_debug {
+            Self::to_token_stream_impl_debug(
+                &self.attr,
+                ident,
+                &new_generics_names,
+                &None,
+                Self::to_token_stream_debug_body(
+                    &name,
+                    true,
+                    non_exhaustive,
+                    self.to_token_stream_debug_fields(Some(quote! { self. })),
+                ),
+            )
+        } else {
+            None
         };
 
         let ts_struct = quote! {
+            #[allow(non_snake_case)]
             #vis mod #async_debug_mod_ident {
-                use super::*;
-
-                #derive
+                #attrs
                 #[allow(dead_code)]
                 #[allow(non_camel_case_types)]
                 #[automatically_derived]
@@ -95,12 +229,15 @@ impl<'a> AsyncDebugStructUnnamed<'a> {
                 (
                     #fields_type
                 );
+
+                #ts_impl_debug
             }
         };
 
         Ok(quote! {
             #ts_impl_async_debug
             #ts_impl_ident
+            #ts_impl_async_debug_nested
             #ts_struct
         })
     }
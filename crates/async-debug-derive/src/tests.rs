@@ -33,7 +33,7 @@ fn test_attr_async_call() {
         }
     };
 
-    let output = async_debug_impl(input).unwrap();
+    let output = async_debug_impl(input, false).unwrap();
 
     file.write_all(fmt(output).as_bytes()).unwrap();
 }
@@ -51,7 +51,7 @@ fn test_attr_clone() {
         }
     };
 
-    let output = async_debug_impl(input).unwrap();
+    let output = async_debug_impl(input, false).unwrap();
 
     file.write_all(fmt(output).as_bytes()).unwrap();
 }
@@ -69,7 +69,7 @@ fn test_attr_copy() {
         }
     };
 
-    let output = async_debug_impl(input).unwrap();
+    let output = async_debug_impl(input, false).unwrap();
 
     file.write_all(fmt(output).as_bytes()).unwrap();
 }
@@ -87,7 +87,7 @@ fn test_attr_ty() {
         }
     };
 
-    let output = async_debug_impl(input).unwrap();
+    let output = async_debug_impl(input, false).unwrap();
 
     file.write_all(fmt(output).as_bytes()).unwrap();
 }
@@ -106,7 +106,7 @@ fn test_no_or_empty_attrs() {
         }
     };
 
-    let output = async_debug_impl(input).unwrap();
+    let output = async_debug_impl(input, false).unwrap();
 
     file.write_all(fmt(output).as_bytes()).unwrap();
 }
@@ -125,7 +125,7 @@ fn test_skip() {
         }
     };
 
-    let output = async_debug_impl(input).unwrap();
+    let output = async_debug_impl(input, false).unwrap();
 
     file.write_all(fmt(output).as_bytes()).unwrap();
 }
@@ -145,7 +145,551 @@ fn test_disable_derive_debug() {
         }
     };
 
-    let output = async_debug_impl(input).unwrap();
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_derive() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_derive.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(derive(Clone, PartialEq), derive(serde::Serialize))]
+        #[async_debug(attr(serde(rename_all = "camelCase")))]
+        struct Input {
+            test: String,
+            #[async_debug(attr(serde(rename = "other")), attr(doc = "Other"))]
+            test_other: u64,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_snapshot() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_snapshot.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(snapshot = InputSnapshot, snapshot_vis = pub(crate), module = input_snapshot)]
+        pub struct Input<'a, T: AsRef<Path>, const N: usize> {
+            test: &'a T,
+            #[async_debug(ty = usize)]
+            len: [u8; N],
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_hygiene_mod_ident() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_hygiene_mod_ident.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Foo_Bar<'async_debug> {
+            test: &'async_debug str,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_hygiene_enum() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_hygiene_enum.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        enum Input {
+            A_b {
+                c: u64,
+                #[async_debug(skip)]
+                skipped: u64,
+            },
+            A {
+                #[async_debug(async_call = lock, clone, ty = u64)]
+                b_c: Mutex<u64>,
+                lock: u64,
+            },
+            B(#[async_debug(skip)] u64, u64),
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_crate() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_crate.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(crate = "my_facade::async_debug", snapshot = InputSnapshot)]
+        struct Input {
+            test: String,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_generics() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_generics.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(bound = "T: Clone", snapshot = InputSnapshot)]
+        struct Input<'a, T: Debug = u8, const N: usize = 4>
+        where
+            for<'x> &'x T: Debug,
+        {
+            name: &'a str,
+            #[async_debug(clone, ty = [T; N])]
+            values: [T; N],
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_send() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_send.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(send)]
+        enum Input {
+            Named {
+                #[async_debug(async_call = RwLock::read, clone, ty = Vec<String>)]
+                rw_lock: RwLock<Vec<String>>,
+            },
+            Unnamed(String, #[async_debug(skip)] u64),
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_nested() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_nested.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(max_depth = 4)]
+        struct Input<T> {
+            #[async_debug(nested)]
+            value: T,
+            #[async_debug(async_call = RwLock::read, nested)]
+            children: RwLock<Vec<Input<T>>>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_limits() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_limits.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(max_depth = 2, max_items = 10)]
+        enum Input {
+            Nested(#[async_debug(nested, max_depth = 1, max_items = 5)] Vec<Input>),
+            Truncated {
+                #[async_debug(async_call = RwLock::read, max_len = 20)]
+                text: RwLock<String>,
+                #[async_debug(truncate)]
+                values: Vec<u64>,
+            },
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_profile() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_profile.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            #[async_debug(profile(brief, skip))]
+            name: String,
+            #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>)]
+            #[async_debug(profile(brief, max_items = 2), profile(full))]
+            values: RwLock<Vec<u64>>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_skip_if() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_skip_if.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        enum Input {
+            Named {
+                #[async_debug(skip_if = Option::is_none)]
+                parent: Option<u64>,
+                #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>, skip_if_empty)]
+                queue: RwLock<Vec<u64>>,
+            },
+            Unnamed(#[async_debug(skip_if_empty)] String, u64),
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_non_exhaustive() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_non_exhaustive.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(non_exhaustive)]
+        enum Input {
+            Named {
+                a: u64,
+                #[async_debug(skip)]
+                b: u64,
+            },
+            #[non_exhaustive]
+            Unnamed(u64),
+            Profile(#[async_debug(profile(brief, skip))] u64),
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_transparent() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_transparent.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(transparent)]
+        struct Input {
+            #[async_debug(async_call = RwLock::read, clone, ty = State)]
+            state: Arc<RwLock<State>>,
+            #[async_debug(skip)]
+            skipped: u64,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_flatten() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_flatten.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            name: String,
+            #[async_debug(flatten)]
+            core: Core,
+            #[async_debug(flatten, profile(brief, skip))]
+            stats: Box<Stats>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_variant() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_variant.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        enum Input {
+            #[async_debug(rename = "Begin")]
+            Start { id: u64 },
+            #[async_debug(skip)]
+            Auth(Secret),
+            #[async_debug(name_only)]
+            Tick { count: u64 },
+            #[async_debug(transparent)]
+            Message(#[async_debug(async_call = RwLock::read, clone, ty = String)] Arc<RwLock<String>>),
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_async_call_pipeline() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint
+        .new_goldenfile("test_attr_async_call_pipeline.rs")
+        .unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            #[async_debug(async_call = [Mutex::lock, each(RwLock::read)], clone, ty = Entry)]
+            entries: Mutex<HashMap<String, Arc<RwLock<Entry>>>>,
+            #[async_debug(async_call = [Mutex::lock, RwLock::read], copy, ty = u64)]
+            version: Mutex<Arc<RwLock<u64>>>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_map() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_map.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            #[async_debug(async_call = Mutex::lock, map = VecDeque::len, ty = usize)]
+            pending: Mutex<VecDeque<String>>,
+            #[async_debug(map = latest, ty = Option<u64>)]
+            timestamps: Vec<u64>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_conversions() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_conversions.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            #[async_debug(async_call = Mutex::lock, to_string)]
+            url: Mutex<Url>,
+            #[async_debug(async_call = RwLock::read, into = PathBuf)]
+            path: RwLock<Box<Path>>,
+            #[async_debug(to_owned)]
+            label: Box<str>,
+            #[async_debug(async_call = RwLock::read, to_owned, ty = String)]
+            name: RwLock<str>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_optional() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_optional.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            #[async_debug(optional, async_call = Mutex::lock, clone, ty = Conn)]
+            conn: Option<Arc<Mutex<Conn>>>,
+            #[async_debug(optional)]
+            name: Option<String>,
+            #[async_debug(optional, async_call = RwLock::read, nested)]
+            child: Option<RwLock<Child>>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_upgrade() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_upgrade.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            #[async_debug(upgrade, async_call = RwLock::read, map = Parent::name, ty = String)]
+            parent: Weak<RwLock<Parent>>,
+            #[async_debug(optional, upgrade, async_call = Mutex::lock, nested)]
+            sibling: Option<rc::Weak<Mutex<Sibling>>>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_try() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_try.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(error = RemoteError)]
+        struct Input {
+            #[async_debug(async_call = Remote::status, try, ty = Status)]
+            status: Remote,
+            #[async_debug(async_call = [Mutex::lock, Remote::peers], try, truncate)]
+            peers: Mutex<Remote>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_field() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_attr_field.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(field(name = "uptime", call = Self::uptime, ty = Duration))]
+        #[async_debug(field(name = "health", async_call = Self::health, to_string, after = "started"))]
+        struct Input {
+            started: Instant,
+            #[async_debug(async_call = Mutex::lock, truncate)]
+            queue: Mutex<Vec<Job>>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_attr_field_vis_getters() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint
+        .new_goldenfile("test_attr_field_vis_getters.rs")
+        .unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        #[async_debug(field_vis = pub(super), getters)]
+        pub(crate) struct Input {
+            name: String,
+            #[async_debug(async_call = Mutex::lock, clone, ty = Vec<u64>, skip_if = Vec::is_empty)]
+            queue: Mutex<Vec<u64>>,
+        }
+    };
+
+    let output = async_debug_impl(input, false).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_serde_struct() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_serde_struct.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        struct Input {
+            #[async_debug(attr(serde(rename = "lock")))]
+            rw_lock: u64,
+            #[async_debug(skip_if = Vec::is_empty)]
+            items: Vec<u64>,
+        }
+    };
+
+    let output = async_debug_impl(input, true).unwrap();
+
+    file.write_all(fmt(output).as_bytes()).unwrap();
+}
+
+#[test]
+fn test_serde_enum() {
+    let mut mint = Mint::new("tests/goldenfiles");
+    let mut file = mint.new_goldenfile("test_serde_enum.rs").unwrap();
+
+    let input = quote! {
+        #[derive(AsyncDebug)]
+        enum Input {
+            Start { id: u64 },
+            Stop(#[async_debug(async_call = Mutex::lock, copy, ty = u64)] Mutex<u64>),
+            #[async_debug(rename = "Paused")]
+            Pause { since: u64 },
+            #[async_debug(skip)]
+            Secret(String),
+            #[async_debug(name_only, rename = "Gone")]
+            Closed { reason: String },
+        }
+    };
+
+    let output = async_debug_impl(input, true).unwrap();
 
     file.write_all(fmt(output).as_bytes()).unwrap();
 }
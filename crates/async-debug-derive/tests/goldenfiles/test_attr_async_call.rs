@@ -1,6 +1,11 @@
 #![rustfmt::skip]
 
-impl AsyncDebug for Input {}
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug RwLock>;
+}
 #[automatically_derived]
 impl Input {
     async fn async_debug(&self) -> async_debug_input::Input<&RwLock> {
@@ -8,14 +13,95 @@ impl Input {
             test: &RwLock::lock(&self.test).await,
         }
     }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
 }
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug RwLock>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["test"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("test") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(RwLock::lock(&self.test)).await {
+                        ::core::result::Result::Ok(value) => {
+                            ::async_debug::Rendered::new(&value)
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("test", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
 mod async_debug_input {
-    use super::*;
-    #[derive(Debug)]
+    #[derive(::core::fmt::Debug)]
     #[allow(dead_code)]
     #[allow(non_camel_case_types)]
     #[automatically_derived]
     pub struct Input<T_AsyncDebug_test> {
         pub(super) test: T_AsyncDebug_test,
     }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<T_AsyncDebug_test: ::core::fmt::Debug> ::async_debug::DebugFields
+    for Input<T_AsyncDebug_test> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.test;
+            debug.field("test", value);
+        }
+    }
 }
@@ -0,0 +1,161 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<::async_debug::Each<Entry>, u64>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<::async_debug::Each<Entry>, u64> {
+        async_debug_input::Input {
+            entries: {
+                let value = Mutex::lock(&self.entries).await;
+                {
+                    use ::async_debug::AsyncDebugEach as _;
+                    let (keys, items) = value.async_debug_each().into_parts();
+                    let mut each = ::std::vec::Vec::with_capacity(items.len());
+                    for value in items {
+                        each.push(RwLock::read(&value).await.clone());
+                    }
+                    ::async_debug::Each::from_parts(keys, each)
+                }
+            },
+            version: {
+                let value = Mutex::lock(&self.version).await;
+                let value = RwLock::read(&value).await;
+                *value
+            },
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<::async_debug::Each<Entry>, u64>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["entries", "version"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("entries") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(Mutex::lock(&self.entries)).await {
+                        ::core::result::Result::Ok(value) => {
+                            use ::async_debug::AsyncDebugEach as _;
+                            let (keys, items) = value.async_debug_each().into_parts();
+                            let mut each = ::std::vec::Vec::with_capacity(items.len());
+                            for value in items {
+                                each.push(
+                                    match async_debug_context.call(RwLock::read(&value)).await {
+                                        ::core::result::Result::Ok(value) => {
+                                            ::async_debug::Rendered::new(&value.clone())
+                                        }
+                                        ::core::result::Result::Err(rendered) => rendered,
+                                    },
+                                );
+                            }
+                            ::async_debug::Rendered::each(
+                                &::async_debug::Each::from_parts(keys, each),
+                            )
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("entries", rendered));
+                let rendered = if async_debug_context.is_redacted("version") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(Mutex::lock(&self.version)).await {
+                        ::core::result::Result::Ok(value) => {
+                            match async_debug_context.call(RwLock::read(&value)).await {
+                                ::core::result::Result::Ok(value) => {
+                                    ::async_debug::Rendered::new(&*value)
+                                }
+                                ::core::result::Result::Err(rendered) => rendered,
+                            }
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("version", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_entries, T_AsyncDebug_version> {
+        pub(super) entries: T_AsyncDebug_entries,
+        pub(super) version: T_AsyncDebug_version,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_entries: ::core::fmt::Debug,
+        T_AsyncDebug_version: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_entries, T_AsyncDebug_version> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.entries;
+            debug.field("entries", value);
+            let value = &self.version;
+            debug.field("version", value);
+        }
+    }
+}
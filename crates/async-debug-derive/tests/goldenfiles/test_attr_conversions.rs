@@ -0,0 +1,183 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        ::std::string::String,
+        PathBuf,
+        <Box<str> as ::std::borrow::ToOwned>::Owned,
+        String,
+    >;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<
+            ::std::string::String,
+            PathBuf,
+            <Box<str> as ::std::borrow::ToOwned>::Owned,
+            String,
+        > {
+        async_debug_input::Input {
+            url: Mutex::lock(&self.url).await.to_string(),
+            path: ::core::convert::Into::<
+                PathBuf,
+            >::into(RwLock::read(&self.path).await.to_owned()),
+            label: self.label.to_owned(),
+            name: RwLock::read(&self.name).await.to_owned(),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        ::std::string::String,
+        PathBuf,
+        <Box<str> as ::std::borrow::ToOwned>::Owned,
+        String,
+    >;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["url", "path", "label", "name"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("url") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(Mutex::lock(&self.url)).await {
+                        ::core::result::Result::Ok(value) => {
+                            ::async_debug::Rendered::new(&value.to_string())
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("url", rendered));
+                let rendered = if async_debug_context.is_redacted("path") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(RwLock::read(&self.path)).await {
+                        ::core::result::Result::Ok(value) => {
+                            ::async_debug::Rendered::new(
+                                &::core::convert::Into::<PathBuf>::into(value.to_owned()),
+                            )
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("path", rendered));
+                let rendered = if async_debug_context.is_redacted("label") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.label.to_owned())
+                };
+                fields.push(("label", rendered));
+                let rendered = if async_debug_context.is_redacted("name") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(RwLock::read(&self.name)).await {
+                        ::core::result::Result::Ok(value) => {
+                            ::async_debug::Rendered::new(&value.to_owned())
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("name", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<
+        T_AsyncDebug_url,
+        T_AsyncDebug_path,
+        T_AsyncDebug_label,
+        T_AsyncDebug_name,
+    > {
+        pub(super) url: T_AsyncDebug_url,
+        pub(super) path: T_AsyncDebug_path,
+        pub(super) label: T_AsyncDebug_label,
+        pub(super) name: T_AsyncDebug_name,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_url: ::core::fmt::Debug,
+        T_AsyncDebug_path: ::core::fmt::Debug,
+        T_AsyncDebug_label: ::core::fmt::Debug,
+        T_AsyncDebug_name: ::core::fmt::Debug,
+    > ::async_debug::DebugFields
+    for Input<
+        T_AsyncDebug_url,
+        T_AsyncDebug_path,
+        T_AsyncDebug_label,
+        T_AsyncDebug_name,
+    > {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.url;
+            debug.field("url", value);
+            let value = &self.path;
+            debug.field("path", value);
+            let value = &self.label;
+            debug.field("label", value);
+            let value = &self.name;
+            debug.field("name", value);
+        }
+    }
+}
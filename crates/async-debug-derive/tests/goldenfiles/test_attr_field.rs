@@ -0,0 +1,183 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        &'async_debug Instant,
+        ::std::string::String,
+        ::async_debug::Rendered,
+        Duration,
+    >;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<
+            &Instant,
+            ::std::string::String,
+            ::async_debug::Rendered,
+            Duration,
+        > {
+        let async_debug_context = ::async_debug::AsyncDebugContext::new(self);
+        async_debug_input::Input {
+            started: &self.started,
+            health: Self::health(&(*self)).await.to_string(),
+            queue: {
+                use ::async_debug::AsyncDebugTruncate as _;
+                Mutex::lock(&self.queue)
+                    .await
+                    .async_debug_truncated(&async_debug_context)
+            },
+            uptime: Self::uptime(&(*self)),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        &'async_debug Instant,
+        ::std::string::String,
+        ::async_debug::Rendered,
+        Duration,
+    >;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["started", "health", "queue", "uptime"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("started") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.started)
+                };
+                fields.push(("started", rendered));
+                let rendered = if async_debug_context.is_redacted("health") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(Self::health(&(*self))).await {
+                        ::core::result::Result::Ok(value) => {
+                            ::async_debug::Rendered::new(&value.to_string())
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("health", rendered));
+                let rendered = if async_debug_context.is_redacted("queue") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    {
+                        use ::async_debug::AsyncDebugTruncate as _;
+                        match async_debug_context.call(Mutex::lock(&self.queue)).await {
+                            ::core::result::Result::Ok(value) => {
+                                value.async_debug_truncated(&async_debug_context)
+                            }
+                            ::core::result::Result::Err(rendered) => rendered,
+                        }
+                    }
+                };
+                fields.push(("queue", rendered));
+                let rendered = if async_debug_context.is_redacted("uptime") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&Self::uptime(&(*self)))
+                };
+                fields.push(("uptime", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<
+        T_AsyncDebug_started,
+        T_AsyncDebug_health,
+        T_AsyncDebug_queue,
+        T_AsyncDebug_uptime,
+    > {
+        pub(super) started: T_AsyncDebug_started,
+        pub(super) health: T_AsyncDebug_health,
+        pub(super) queue: T_AsyncDebug_queue,
+        pub(super) uptime: T_AsyncDebug_uptime,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_started: ::core::fmt::Debug,
+        T_AsyncDebug_health: ::core::fmt::Debug,
+        T_AsyncDebug_queue: ::core::fmt::Debug,
+        T_AsyncDebug_uptime: ::core::fmt::Debug,
+    > ::async_debug::DebugFields
+    for Input<
+        T_AsyncDebug_started,
+        T_AsyncDebug_health,
+        T_AsyncDebug_queue,
+        T_AsyncDebug_uptime,
+    > {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.started;
+            debug.field("started", value);
+            let value = &self.health;
+            debug.field("health", value);
+            let value = &self.queue;
+            debug.field("queue", value);
+            let value = &self.uptime;
+            debug.field("uptime", value);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug String, Vec<u64>>;
+}
+#[automatically_derived]
+impl Input {
+    pub(crate) async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<&String, Vec<u64>> {
+        async_debug_input::Input {
+            name: &self.name,
+            queue: {
+                let value = Mutex::lock(&self.queue).await;
+                if Vec::is_empty(&value) {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some(value.clone())
+                }
+            },
+        }
+    }
+    pub(crate) async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug String, Vec<u64>>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["name", "queue"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("name") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.name)
+                };
+                fields.push(("name", rendered));
+                if let ::core::option::Option::Some(rendered)
+                    = if async_debug_context.is_redacted("queue") {
+                        ::core::option::Option::Some(::async_debug::Rendered::redacted())
+                    } else {
+                        match async_debug_context.call(Mutex::lock(&self.queue)).await {
+                            ::core::result::Result::Ok(value) => {
+                                if Vec::is_empty(&value) {
+                                    ::core::option::Option::None
+                                } else {
+                                    ::core::option::Option::Some(
+                                        ::async_debug::Rendered::new(&value.clone()),
+                                    )
+                                }
+                            }
+                            ::core::result::Result::Err(rendered) => {
+                                ::core::option::Option::Some(rendered)
+                            }
+                        }
+                    } {
+                    fields.push(("queue", rendered));
+                }
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+pub(crate) mod async_debug_input {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_name, T_AsyncDebug_queue> {
+        pub(in super::super) name: T_AsyncDebug_name,
+        pub(in super::super) queue: ::core::option::Option<T_AsyncDebug_queue>,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_name: ::core::fmt::Debug,
+        T_AsyncDebug_queue: ::core::fmt::Debug,
+    > ::core::fmt::Debug for Input<T_AsyncDebug_name, T_AsyncDebug_queue> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            {
+                let mut debug = f.debug_struct("Input");
+                ::async_debug::DebugFields::debug_fields(self, &mut debug);
+                debug.finish()
+            }
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_name: ::core::fmt::Debug,
+        T_AsyncDebug_queue: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_name, T_AsyncDebug_queue> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.name;
+            debug.field("name", value);
+            if let ::core::option::Option::Some(value) = &self.queue {
+                debug.field("queue", value);
+            }
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_name,
+        T_AsyncDebug_queue,
+    > Input<T_AsyncDebug_name, T_AsyncDebug_queue> {
+        pub(crate) fn name(&self) -> &T_AsyncDebug_name {
+            &self.name
+        }
+        pub(crate) fn queue(&self) -> &::core::option::Option<T_AsyncDebug_queue> {
+            &self.queue
+        }
+    }
+}
@@ -0,0 +1,207 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        &'async_debug String,
+        <Core as ::async_debug::AsyncDebugFlatten>::Snapshot<'async_debug>,
+        <Box<Stats> as ::async_debug::AsyncDebugFlatten>::Snapshot<'async_debug>,
+    >;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<
+            &String,
+            <Core as ::async_debug::AsyncDebugFlatten>::Snapshot<'_>,
+            <Box<Stats> as ::async_debug::AsyncDebugFlatten>::Snapshot<'_>,
+        > {
+        async_debug_input::Input {
+            name: &self.name,
+            core: {
+                use ::async_debug::AsyncDebugFlatten as _;
+                ::async_debug::AsyncDebugFlatten::async_debug_flatten_snapshot(
+                        &self.core,
+                    )
+                    .await
+            },
+            stats: {
+                use ::async_debug::AsyncDebugFlatten as _;
+                ::async_debug::AsyncDebugFlatten::async_debug_flatten_snapshot(
+                        &self.stats,
+                    )
+                    .await
+            },
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+    async fn async_debug_brief(&self) -> ::async_debug::Rendered {
+        let options = ::async_debug::AsyncDebugOptions::new().with_profile("brief");
+        self.async_debug_with(&options).await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        &'async_debug String,
+        <Core as ::async_debug::AsyncDebugFlatten>::Snapshot<'async_debug>,
+        <Box<Stats> as ::async_debug::AsyncDebugFlatten>::Snapshot<'async_debug>,
+    >;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["name"],
+        flattened: &[
+            &<Core as ::async_debug::AsyncDebugFlatten>::FIELD_NAMES,
+            &<Box<Stats> as ::async_debug::AsyncDebugFlatten>::FIELD_NAMES,
+        ],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("name") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.name)
+                };
+                fields.push(("name", rendered));
+                let rendered = if async_debug_context.is_redacted("core") {
+                    ::async_debug::RenderedFields::redacted("core")
+                } else {
+                    {
+                        use ::async_debug::AsyncDebugFlatten as _;
+                        self.core.async_debug_flatten(&async_debug_context).await
+                    }
+                };
+                fields.extend(rendered);
+                match async_debug_context.profile() {
+                    ::core::option::Option::Some("brief") => {}
+                    _ => {
+                        let rendered = if async_debug_context.is_redacted("stats") {
+                            ::async_debug::RenderedFields::redacted("stats")
+                        } else {
+                            {
+                                use ::async_debug::AsyncDebugFlatten as _;
+                                self.stats.async_debug_flatten(&async_debug_context).await
+                            }
+                        };
+                        fields.extend(rendered);
+                    }
+                }
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+const _: () = {
+    ::core::assert!(
+        ! < Core as ::async_debug::AsyncDebugFlatten > ::FIELD_NAMES.contains("name"),
+        "flattened field `core` of `Input` has a field named `name`, like another field of `Input`"
+    );
+    ::core::assert!(
+        ! < Box < Stats > as ::async_debug::AsyncDebugFlatten > ::FIELD_NAMES
+        .contains("name"),
+        "flattened field `stats` of `Input` has a field named `name`, like another field of `Input`"
+    );
+    ::core::assert!(
+        ! < Box < Stats > as ::async_debug::AsyncDebugFlatten > ::FIELD_NAMES
+        .intersects(& < Core as ::async_debug::AsyncDebugFlatten > ::FIELD_NAMES),
+        "flattened fields `core` and `stats` of `Input` have fields named alike"
+    );
+};
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_name, T_AsyncDebug_core, T_AsyncDebug_stats> {
+        pub(super) name: T_AsyncDebug_name,
+        pub(super) core: T_AsyncDebug_core,
+        pub(super) stats: T_AsyncDebug_stats,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_name: ::core::fmt::Debug,
+        T_AsyncDebug_core: ::core::fmt::Debug,
+        T_AsyncDebug_stats: ::core::fmt::Debug,
+    > ::core::fmt::Debug
+    for Input<T_AsyncDebug_name, T_AsyncDebug_core, T_AsyncDebug_stats>
+    where
+        T_AsyncDebug_core: ::async_debug::DebugFields,
+        T_AsyncDebug_stats: ::async_debug::DebugFields,
+    {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            {
+                let mut debug = f.debug_struct("Input");
+                ::async_debug::DebugFields::debug_fields(self, &mut debug);
+                debug.finish()
+            }
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_name: ::core::fmt::Debug,
+        T_AsyncDebug_core: ::core::fmt::Debug,
+        T_AsyncDebug_stats: ::core::fmt::Debug,
+    > ::async_debug::DebugFields
+    for Input<T_AsyncDebug_name, T_AsyncDebug_core, T_AsyncDebug_stats>
+    where
+        T_AsyncDebug_core: ::async_debug::DebugFields,
+        T_AsyncDebug_stats: ::async_debug::DebugFields,
+    {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.name;
+            debug.field("name", value);
+            ::async_debug::DebugFields::debug_fields(&self.core, debug);
+            ::async_debug::DebugFields::debug_fields(&self.stats, debug);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        ::async_debug::Rendered,
+        ::async_debug::Rendered,
+        ::async_debug::Rendered,
+    >;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<
+            ::async_debug::Rendered,
+            ::async_debug::Rendered,
+            ::async_debug::Rendered,
+        > {
+        let async_debug_context = ::async_debug::AsyncDebugContext::new(self)
+            .with_max_depth(2usize)
+            .with_max_items(10usize);
+        match self {
+            Self::Nested(self_0) => {
+                async_debug_input::Input::Nested({
+                    use ::async_debug::AsyncDebugNested as _;
+                    let nested_context = async_debug_context
+                        .nested()
+                        .with_max_depth(1usize)
+                        .with_max_items(5usize);
+                    if nested_context.is_past_max_depth() {
+                        ::async_debug::Rendered::elided()
+                    } else {
+                        self_0.async_debug_nested(&nested_context).await
+                    }
+                })
+            }
+            Self::Truncated { text: self_text, values: self_values, .. } => {
+                async_debug_input::Input::Truncated {
+                    text: {
+                        use ::async_debug::AsyncDebugTruncate as _;
+                        RwLock::read(&self_text)
+                            .await
+                            .async_debug_truncated(
+                                &async_debug_context.clone().with_max_len(20usize),
+                            )
+                    },
+                    values: {
+                        use ::async_debug::AsyncDebugTruncate as _;
+                        self_values.async_debug_truncated(&async_debug_context)
+                    },
+                }
+            }
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_max_depth(2usize)
+            .with_max_items(10usize)
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    #[allow(refining_impl_trait)]
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> ::async_debug::BoxFuture<'async_debug, ::async_debug::Rendered> {
+        ::std::boxed::Box::pin(async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            match self {
+                Self::Nested(self_0) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("0") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        {
+                            use ::async_debug::AsyncDebugNested as _;
+                            let nested_context = async_debug_context
+                                .nested()
+                                .with_max_depth(1usize)
+                                .with_max_items(5usize);
+                            if nested_context.is_past_max_depth() {
+                                ::async_debug::Rendered::elided()
+                            } else {
+                                self_0.async_debug_nested(&nested_context).await
+                            }
+                        }
+                    };
+                    fields.push(rendered);
+                    ::async_debug::Rendered::debug_tuple("Nested", &fields)
+                }
+                Self::Truncated { text: self_text, values: self_values, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("text") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        {
+                            use ::async_debug::AsyncDebugTruncate as _;
+                            match async_debug_context
+                                .call(RwLock::read(&self_text))
+                                .await
+                            {
+                                ::core::result::Result::Ok(value) => {
+                                    value
+                                        .async_debug_truncated(
+                                            &async_debug_context.clone().with_max_len(20usize),
+                                        )
+                                }
+                                ::core::result::Result::Err(rendered) => rendered,
+                            }
+                        }
+                    };
+                    fields.push(("text", rendered));
+                    let rendered = if async_debug_context.is_redacted("values") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        {
+                            use ::async_debug::AsyncDebugTruncate as _;
+                            self_values.async_debug_truncated(&async_debug_context)
+                        }
+                    };
+                    fields.push(("values", rendered));
+                    ::async_debug::Rendered::debug_struct("Truncated", &fields)
+                }
+            }
+        })
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub enum Input<
+        T_AsyncDebug_0_Nested_0,
+        T_AsyncDebug_1_Truncated_text,
+        T_AsyncDebug_1_Truncated_values,
+    > {
+        Nested(T_AsyncDebug_0_Nested_0),
+        Truncated {
+            text: T_AsyncDebug_1_Truncated_text,
+            values: T_AsyncDebug_1_Truncated_values,
+        },
+    }
+}
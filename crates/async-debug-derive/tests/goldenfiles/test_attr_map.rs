@@ -0,0 +1,123 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<usize, Option<u64>>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<usize, Option<u64>> {
+        async_debug_input::Input {
+            pending: {
+                let value = Mutex::lock(&self.pending).await;
+                VecDeque::len(&value)
+            },
+            timestamps: latest(&self.timestamps),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<usize, Option<u64>>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["pending", "timestamps"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("pending") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(Mutex::lock(&self.pending)).await {
+                        ::core::result::Result::Ok(value) => {
+                            ::async_debug::Rendered::new(&VecDeque::len(&value))
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("pending", rendered));
+                let rendered = if async_debug_context.is_redacted("timestamps") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&latest(&self.timestamps))
+                };
+                fields.push(("timestamps", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_pending, T_AsyncDebug_timestamps> {
+        pub(super) pending: T_AsyncDebug_pending,
+        pub(super) timestamps: T_AsyncDebug_timestamps,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_pending: ::core::fmt::Debug,
+        T_AsyncDebug_timestamps: ::core::fmt::Debug,
+    > ::async_debug::DebugFields
+    for Input<T_AsyncDebug_pending, T_AsyncDebug_timestamps> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.pending;
+            debug.field("pending", value);
+            let value = &self.timestamps;
+            debug.field("timestamps", value);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+#![rustfmt::skip]
+
+impl<T> ::async_debug::AsyncDebug for Input<T>
+where
+    T: ::async_debug::AsyncDebugNested,
+{
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<::async_debug::Rendered, ::async_debug::Rendered>;
+}
+#[automatically_derived]
+impl<T> Input<T>
+where
+    T: ::async_debug::AsyncDebugNested,
+{
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<::async_debug::Rendered, ::async_debug::Rendered> {
+        let async_debug_context = ::async_debug::AsyncDebugContext::new(self)
+            .with_max_depth(4usize);
+        async_debug_input::Input {
+            value: {
+                use ::async_debug::AsyncDebugNested as _;
+                let nested_context = async_debug_context.nested();
+                if nested_context.is_past_max_depth() {
+                    ::async_debug::Rendered::elided()
+                } else {
+                    self.value.async_debug_nested(&nested_context).await
+                }
+            },
+            children: {
+                use ::async_debug::AsyncDebugNested as _;
+                let nested_context = async_debug_context.nested();
+                if nested_context.is_past_max_depth() {
+                    ::async_debug::Rendered::elided()
+                } else {
+                    {
+                        let target = ::async_debug::async_call_target(
+                            RwLock::read,
+                            &self.children,
+                        );
+                        match nested_context.enter(target) {
+                            ::core::result::Result::Ok(nested_context) => {
+                                let value = RwLock::read(target).await;
+                                value.async_debug_nested(&nested_context).await
+                            }
+                            ::core::result::Result::Err(cycle) => cycle,
+                        }
+                    }
+                }
+            },
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_max_depth(4usize)
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl<T> ::async_debug::AsyncDebugNested for Input<T>
+where
+    T: ::async_debug::AsyncDebugNested,
+{
+    #[allow(refining_impl_trait)]
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> ::async_debug::BoxFuture<'async_debug, ::async_debug::Rendered> {
+        ::std::boxed::Box::pin(async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        })
+    }
+}
+#[automatically_derived]
+impl<T> ::async_debug::AsyncDebugFlatten for Input<T>
+where
+    T: ::async_debug::AsyncDebugNested,
+{
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<::async_debug::Rendered, ::async_debug::Rendered>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["value", "children"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("value") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    {
+                        use ::async_debug::AsyncDebugNested as _;
+                        let nested_context = async_debug_context.nested();
+                        if nested_context.is_past_max_depth() {
+                            ::async_debug::Rendered::elided()
+                        } else {
+                            self.value.async_debug_nested(&nested_context).await
+                        }
+                    }
+                };
+                fields.push(("value", rendered));
+                let rendered = if async_debug_context.is_redacted("children") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    {
+                        use ::async_debug::AsyncDebugNested as _;
+                        let nested_context = async_debug_context.nested();
+                        if nested_context.is_past_max_depth() {
+                            ::async_debug::Rendered::elided()
+                        } else {
+                            {
+                                let target = ::async_debug::async_call_target(
+                                    RwLock::read,
+                                    &self.children,
+                                );
+                                match nested_context.enter(target) {
+                                    ::core::result::Result::Ok(nested_context) => {
+                                        match async_debug_context.call(RwLock::read(target)).await {
+                                            ::core::result::Result::Ok(value) => {
+                                                value.async_debug_nested(&nested_context).await
+                                            }
+                                            ::core::result::Result::Err(rendered) => rendered,
+                                        }
+                                    }
+                                    ::core::result::Result::Err(cycle) => cycle,
+                                }
+                            }
+                        }
+                    }
+                };
+                fields.push(("children", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_value, T_AsyncDebug_children> {
+        pub(super) value: T_AsyncDebug_value,
+        pub(super) children: T_AsyncDebug_children,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_value: ::core::fmt::Debug,
+        T_AsyncDebug_children: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_value, T_AsyncDebug_children> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.value;
+            debug.field("value", value);
+            let value = &self.children;
+            debug.field("children", value);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug u64, &'async_debug u64, &'async_debug u64>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<&u64, &u64, &u64> {
+        match self {
+            Self::Named { a: self_a, .. } => {
+                async_debug_input::Input::Named {
+                    a: &self_a,
+                }
+            }
+            Self::Unnamed(self_0) => async_debug_input::Input::Unnamed(&self_0),
+            Self::Profile(self_0) => async_debug_input::Input::Profile(&self_0),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+    async fn async_debug_brief(&self) -> ::async_debug::Rendered {
+        let options = ::async_debug::AsyncDebugOptions::new().with_profile("brief");
+        self.async_debug_with(&options).await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            match self {
+                Self::Named { a: self_a, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("a") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_a)
+                    };
+                    fields.push(("a", rendered));
+                    ::async_debug::Rendered::debug_struct_non_exhaustive(
+                        "Named",
+                        &fields,
+                    )
+                }
+                Self::Unnamed(self_0) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("0") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_0)
+                    };
+                    fields.push(rendered);
+                    ::async_debug::Rendered::debug_tuple_non_exhaustive(
+                        "Unnamed",
+                        &fields,
+                    )
+                }
+                Self::Profile(self_0) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    let mut non_exhaustive = false;
+                    match async_debug_context.profile() {
+                        ::core::option::Option::Some("brief") => {
+                            non_exhaustive = true;
+                        }
+                        _ => {
+                            let rendered = if async_debug_context.is_redacted("0") {
+                                ::async_debug::Rendered::redacted()
+                            } else {
+                                ::async_debug::Rendered::new(&self_0)
+                            };
+                            fields.push(rendered);
+                        }
+                    }
+                    if non_exhaustive {
+                        ::async_debug::Rendered::debug_tuple_non_exhaustive(
+                            "Profile",
+                            &fields,
+                        )
+                    } else {
+                        ::async_debug::Rendered::debug_tuple("Profile", &fields)
+                    }
+                }
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub enum Input<
+        T_AsyncDebug_0_Named_a,
+        T_AsyncDebug_1_Unnamed_0,
+        T_AsyncDebug_2_Profile_0,
+    > {
+        Named { a: T_AsyncDebug_0_Named_a },
+        Unnamed(T_AsyncDebug_1_Unnamed_0),
+        Profile(T_AsyncDebug_2_Profile_0),
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_0_Named_a: ::core::fmt::Debug,
+        T_AsyncDebug_1_Unnamed_0: ::core::fmt::Debug,
+        T_AsyncDebug_2_Profile_0: ::core::fmt::Debug,
+    > ::core::fmt::Debug
+    for Input<
+        T_AsyncDebug_0_Named_a,
+        T_AsyncDebug_1_Unnamed_0,
+        T_AsyncDebug_2_Profile_0,
+    > {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::Named { a: self_a } => {
+                    let mut debug = f.debug_struct("Named");
+                    let value = &self_a;
+                    debug.field("a", value);
+                    debug.finish_non_exhaustive()
+                }
+                Self::Unnamed(self_0) => {
+                    let mut debug = f.debug_tuple("Unnamed");
+                    let value = &self_0;
+                    debug.field(value);
+                    debug.finish_non_exhaustive()
+                }
+                Self::Profile(self_0) => {
+                    let mut debug = f.debug_tuple("Profile");
+                    let value = &self_0;
+                    debug.field(value);
+                    debug.finish()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug String, Vec<u64>>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<&String, Vec<u64>> {
+        async_debug_input::Input {
+            name: &self.name,
+            values: RwLock::read(&self.values).await.clone(),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+    async fn async_debug_brief(&self) -> ::async_debug::Rendered {
+        let options = ::async_debug::AsyncDebugOptions::new().with_profile("brief");
+        self.async_debug_with(&options).await
+    }
+    async fn async_debug_full(&self) -> ::async_debug::Rendered {
+        let options = ::async_debug::AsyncDebugOptions::new().with_profile("full");
+        self.async_debug_with(&options).await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug String, Vec<u64>>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["name", "values"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                match async_debug_context.profile() {
+                    ::core::option::Option::Some("brief") => {}
+                    _ => {
+                        let rendered = if async_debug_context.is_redacted("name") {
+                            ::async_debug::Rendered::redacted()
+                        } else {
+                            ::async_debug::Rendered::new(&self.name)
+                        };
+                        fields.push(("name", rendered));
+                    }
+                }
+                match async_debug_context.profile() {
+                    ::core::option::Option::Some("brief") => {
+                        let rendered = if async_debug_context.is_redacted("values") {
+                            ::async_debug::Rendered::redacted()
+                        } else {
+                            {
+                                use ::async_debug::AsyncDebugTruncate as _;
+                                match async_debug_context
+                                    .call(RwLock::read(&self.values))
+                                    .await
+                                {
+                                    ::core::result::Result::Ok(value) => {
+                                        value
+                                            .async_debug_truncated(
+                                                &async_debug_context.clone().with_max_items(2usize),
+                                            )
+                                    }
+                                    ::core::result::Result::Err(rendered) => rendered,
+                                }
+                            }
+                        };
+                        fields.push(("values", rendered));
+                    }
+                    ::core::option::Option::Some("full") => {
+                        let rendered = if async_debug_context.is_redacted("values") {
+                            ::async_debug::Rendered::redacted()
+                        } else {
+                            match async_debug_context
+                                .call(RwLock::read(&self.values))
+                                .await
+                            {
+                                ::core::result::Result::Ok(value) => {
+                                    ::async_debug::Rendered::new(&value.clone())
+                                }
+                                ::core::result::Result::Err(rendered) => rendered,
+                            }
+                        };
+                        fields.push(("values", rendered));
+                    }
+                    _ => {
+                        let rendered = if async_debug_context.is_redacted("values") {
+                            ::async_debug::Rendered::redacted()
+                        } else {
+                            match async_debug_context
+                                .call(RwLock::read(&self.values))
+                                .await
+                            {
+                                ::core::result::Result::Ok(value) => {
+                                    ::async_debug::Rendered::new(&value.clone())
+                                }
+                                ::core::result::Result::Err(rendered) => rendered,
+                            }
+                        };
+                        fields.push(("values", rendered));
+                    }
+                }
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_name, T_AsyncDebug_values> {
+        pub(super) name: T_AsyncDebug_name,
+        pub(super) values: T_AsyncDebug_values,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_name: ::core::fmt::Debug,
+        T_AsyncDebug_values: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_name, T_AsyncDebug_values> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.name;
+            debug.field("name", value);
+            let value = &self.values;
+            debug.field("values", value);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<Vec<String>, &'async_debug String>;
+}
+#[automatically_derived]
+impl Input {
+    fn async_debug(
+        &self,
+    ) -> impl ::core::future::Future<
+            Output = async_debug_input::Input<Vec<String>, &String>,
+        > + ::core::marker::Send + '_ {
+        ::async_debug::assert_field_is_sync::<RwLock<Vec<String>>>();
+        ::async_debug::assert_field_is_sync::<String>();
+        ::async_debug::assert_field_is_sync::<u64>();
+        async move {
+            let snapshot: async_debug_input::Input<Vec<String>, &String> = {
+                match self {
+                    Self::Named { rw_lock: self_rw_lock, .. } => {
+                        async_debug_input::Input::Named {
+                            rw_lock: RwLock::read(&self_rw_lock).await.clone(),
+                        }
+                    }
+                    Self::Unnamed(self_0, _) => {
+                        async_debug_input::Input::Unnamed(&self_0)
+                    }
+                }
+            };
+            snapshot
+        }
+    }
+    fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::Rendered,
+        > + ::core::marker::Send + 'async_debug {
+        ::async_debug::assert_field_is_sync::<RwLock<Vec<String>>>();
+        ::async_debug::assert_field_is_sync::<String>();
+        ::async_debug::assert_field_is_sync::<u64>();
+        async move {
+            let snapshot: ::async_debug::Rendered = {
+                let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+                    .with_options(options);
+                ::async_debug::AsyncDebugNested::async_debug_nested(
+                        self,
+                        &async_debug_context,
+                    )
+                    .await
+            };
+            snapshot
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            match self {
+                Self::Named { rw_lock: self_rw_lock, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("rw_lock") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        match async_debug_context.call(RwLock::read(&self_rw_lock)).await
+                        {
+                            ::core::result::Result::Ok(value) => {
+                                ::async_debug::Rendered::new(&value.clone())
+                            }
+                            ::core::result::Result::Err(rendered) => rendered,
+                        }
+                    };
+                    fields.push(("rw_lock", rendered));
+                    ::async_debug::Rendered::debug_struct("Named", &fields)
+                }
+                Self::Unnamed(self_0, _) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("0") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_0)
+                    };
+                    fields.push(rendered);
+                    ::async_debug::Rendered::debug_tuple("Unnamed", &fields)
+                }
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub enum Input<T_AsyncDebug_0_Named_rw_lock, T_AsyncDebug_1_Unnamed_0> {
+        Named { rw_lock: T_AsyncDebug_0_Named_rw_lock },
+        Unnamed(T_AsyncDebug_1_Unnamed_0),
+    }
+}
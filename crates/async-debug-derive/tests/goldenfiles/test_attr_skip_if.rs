@@ -0,0 +1,207 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        &'async_debug Option<u64>,
+        Vec<u64>,
+        &'async_debug String,
+        &'async_debug u64,
+    >;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<&Option<u64>, Vec<u64>, &String, &u64> {
+        match self {
+            Self::Named { parent: self_parent, queue: self_queue, .. } => {
+                async_debug_input::Input::Named {
+                    parent: if Option::is_none(&self_parent) {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(&self_parent)
+                    },
+                    queue: {
+                        let value = RwLock::read(&self_queue).await;
+                        if value.is_empty() {
+                            ::core::option::Option::None
+                        } else {
+                            ::core::option::Option::Some(value.clone())
+                        }
+                    },
+                }
+            }
+            Self::Unnamed(self_0, self_1) => {
+                async_debug_input::Input::Unnamed(
+                    if self_0.is_empty() {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(&self_0)
+                    },
+                    &self_1,
+                )
+            }
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            match self {
+                Self::Named { parent: self_parent, queue: self_queue, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    if let ::core::option::Option::Some(rendered)
+                        = if async_debug_context.is_redacted("parent") {
+                            ::core::option::Option::Some(
+                                ::async_debug::Rendered::redacted(),
+                            )
+                        } else {
+                            if Option::is_none(&self_parent) {
+                                ::core::option::Option::None
+                            } else {
+                                ::core::option::Option::Some(
+                                    ::async_debug::Rendered::new(&self_parent),
+                                )
+                            }
+                        } {
+                        fields.push(("parent", rendered));
+                    }
+                    if let ::core::option::Option::Some(rendered)
+                        = if async_debug_context.is_redacted("queue") {
+                            ::core::option::Option::Some(
+                                ::async_debug::Rendered::redacted(),
+                            )
+                        } else {
+                            match async_debug_context
+                                .call(RwLock::read(&self_queue))
+                                .await
+                            {
+                                ::core::result::Result::Ok(value) => {
+                                    if value.is_empty() {
+                                        ::core::option::Option::None
+                                    } else {
+                                        ::core::option::Option::Some(
+                                            ::async_debug::Rendered::new(&value.clone()),
+                                        )
+                                    }
+                                }
+                                ::core::result::Result::Err(rendered) => {
+                                    ::core::option::Option::Some(rendered)
+                                }
+                            }
+                        } {
+                        fields.push(("queue", rendered));
+                    }
+                    ::async_debug::Rendered::debug_struct("Named", &fields)
+                }
+                Self::Unnamed(self_0, self_1) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    if let ::core::option::Option::Some(rendered)
+                        = if async_debug_context.is_redacted("0") {
+                            ::core::option::Option::Some(
+                                ::async_debug::Rendered::redacted(),
+                            )
+                        } else {
+                            if self_0.is_empty() {
+                                ::core::option::Option::None
+                            } else {
+                                ::core::option::Option::Some(
+                                    ::async_debug::Rendered::new(&self_0),
+                                )
+                            }
+                        } {
+                        fields.push(rendered);
+                    }
+                    let rendered = if async_debug_context.is_redacted("1") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_1)
+                    };
+                    fields.push(rendered);
+                    ::async_debug::Rendered::debug_tuple("Unnamed", &fields)
+                }
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub enum Input<
+        T_AsyncDebug_0_Named_parent,
+        T_AsyncDebug_0_Named_queue,
+        T_AsyncDebug_1_Unnamed_0,
+        T_AsyncDebug_1_Unnamed_1,
+    > {
+        Named {
+            parent: ::core::option::Option<T_AsyncDebug_0_Named_parent>,
+            queue: ::core::option::Option<T_AsyncDebug_0_Named_queue>,
+        },
+        Unnamed(
+            ::core::option::Option<T_AsyncDebug_1_Unnamed_0>,
+            T_AsyncDebug_1_Unnamed_1,
+        ),
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_0_Named_parent: ::core::fmt::Debug,
+        T_AsyncDebug_0_Named_queue: ::core::fmt::Debug,
+        T_AsyncDebug_1_Unnamed_0: ::core::fmt::Debug,
+        T_AsyncDebug_1_Unnamed_1: ::core::fmt::Debug,
+    > ::core::fmt::Debug
+    for Input<
+        T_AsyncDebug_0_Named_parent,
+        T_AsyncDebug_0_Named_queue,
+        T_AsyncDebug_1_Unnamed_0,
+        T_AsyncDebug_1_Unnamed_1,
+    > {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::Named { parent: self_parent, queue: self_queue } => {
+                    let mut debug = f.debug_struct("Named");
+                    if let ::core::option::Option::Some(value) = &self_parent {
+                        debug.field("parent", value);
+                    }
+                    if let ::core::option::Option::Some(value) = &self_queue {
+                        debug.field("queue", value);
+                    }
+                    debug.finish()
+                }
+                Self::Unnamed(self_0, self_1) => {
+                    let mut debug = f.debug_tuple("Unnamed");
+                    if let ::core::option::Option::Some(value) = &self_0 {
+                        debug.field(value);
+                    }
+                    let value = &self_1;
+                    debug.field(value);
+                    debug.finish()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+#![rustfmt::skip]
+
+impl<'a, T: AsRef<Path>, const N: usize> ::async_debug::AsyncDebug for Input<'a, T, N> {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = input_snapshot::Input<&'async_debug &'a T, usize>;
+}
+pub(crate) type InputSnapshot<'async_debug, 'a, T, const N: usize> = <Input<
+    'a,
+    T,
+    N,
+> as ::async_debug::AsyncDebug>::Snapshot<'async_debug>;
+#[automatically_derived]
+impl<'a, T: AsRef<Path>, const N: usize> Input<'a, T, N> {
+    pub async fn async_debug(&self) -> input_snapshot::Input<&&'a T, usize> {
+        input_snapshot::Input {
+            test: &self.test,
+            len: self.len,
+        }
+    }
+    pub async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered
+    where
+        T: ::core::fmt::Debug,
+    {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl<'a, T: AsRef<Path>, const N: usize> ::async_debug::AsyncDebugNested
+for Input<'a, T, N>
+where
+    T: ::core::fmt::Debug,
+{
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl<'a, T: AsRef<Path>, const N: usize> ::async_debug::AsyncDebugFlatten
+for Input<'a, T, N>
+where
+    T: ::core::fmt::Debug,
+{
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = input_snapshot::Input<&'async_debug &'a T, usize>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["test", "len"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("test") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.test)
+                };
+                fields.push(("test", rendered));
+                let rendered = if async_debug_context.is_redacted("len") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.len)
+                };
+                fields.push(("len", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+pub mod input_snapshot {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_test, T_AsyncDebug_len> {
+        pub(super) test: T_AsyncDebug_test,
+        pub(super) len: T_AsyncDebug_len,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_test: ::core::fmt::Debug,
+        T_AsyncDebug_len: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_test, T_AsyncDebug_len> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.test;
+            debug.field("test", value);
+            let value = &self.len;
+            debug.field("len", value);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<State>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<State> {
+        async_debug_input::Input {
+            state: RwLock::read(&self.state).await.clone(),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            if async_debug_context.is_redacted("state") {
+                ::async_debug::Rendered::redacted()
+            } else {
+                match async_debug_context.call(RwLock::read(&self.state)).await {
+                    ::core::result::Result::Ok(value) => {
+                        ::async_debug::Rendered::new(&value.clone())
+                    }
+                    ::core::result::Result::Err(rendered) => rendered,
+                }
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_state> {
+        pub(super) state: T_AsyncDebug_state,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<T_AsyncDebug_state: ::core::fmt::Debug> ::core::fmt::Debug
+    for Input<T_AsyncDebug_state> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Debug::fmt(&self.state, f)
+        }
+    }
+}
@@ -0,0 +1,227 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        ::core::result::Result<Status, ::async_debug::Rendered>,
+        ::async_debug::Rendered,
+    >;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<
+            ::core::result::Result<Status, ::async_debug::Rendered>,
+            ::async_debug::Rendered,
+        > {
+        let async_debug_context = ::async_debug::AsyncDebugContext::new(self);
+        async_debug_input::Input {
+            status: match Remote::status(&self.status).await {
+                ::core::result::Result::Ok(ok) => ::core::result::Result::Ok(ok),
+                ::core::result::Result::Err(error) => {
+                    ::core::result::Result::Err(::async_debug::Rendered::new(&error))
+                }
+            },
+            peers: {
+                use ::async_debug::AsyncDebugTruncate as _;
+                {
+                    let value = Mutex::lock(&self.peers).await;
+                    let value = Remote::peers(&value).await;
+                    match value {
+                        ::core::result::Result::Ok(ok) => {
+                            ::async_debug::Rendered::ok(
+                                ok.async_debug_truncated(&async_debug_context),
+                            )
+                        }
+                        ::core::result::Result::Err(error) => {
+                            ::async_debug::Rendered::err(
+                                ::async_debug::Rendered::new(&error),
+                            )
+                        }
+                    }
+                }
+            },
+        }
+    }
+    async fn try_async_debug(
+        &self,
+    ) -> ::core::result::Result<
+            async_debug_input::Input<Status, ::async_debug::Rendered>,
+            RemoteError,
+        > {
+        let async_debug_context = ::async_debug::AsyncDebugContext::new(self);
+        ::core::result::Result::Ok({
+            async_debug_input::Input {
+                status: match Remote::status(&self.status).await {
+                    ::core::result::Result::Ok(ok) => ok,
+                    ::core::result::Result::Err(error) => {
+                        return ::core::result::Result::Err(
+                            ::core::convert::From::from(error),
+                        );
+                    }
+                },
+                peers: {
+                    use ::async_debug::AsyncDebugTruncate as _;
+                    {
+                        let value = Mutex::lock(&self.peers).await;
+                        let value = Remote::peers(&value).await;
+                        match value {
+                            ::core::result::Result::Ok(ok) => {
+                                ok.async_debug_truncated(&async_debug_context)
+                            }
+                            ::core::result::Result::Err(error) => {
+                                return ::core::result::Result::Err(
+                                    ::core::convert::From::from(error),
+                                );
+                            }
+                        }
+                    }
+                },
+            }
+        })
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        ::core::result::Result<Status, ::async_debug::Rendered>,
+        ::async_debug::Rendered,
+    >;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["status", "peers"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("status") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match async_debug_context.call(Remote::status(&self.status)).await {
+                        ::core::result::Result::Ok(value) => {
+                            match value {
+                                ::core::result::Result::Ok(ok) => {
+                                    ::async_debug::Rendered::ok(
+                                        ::async_debug::Rendered::new(&ok),
+                                    )
+                                }
+                                ::core::result::Result::Err(error) => {
+                                    ::async_debug::Rendered::err(
+                                        ::async_debug::Rendered::new(&error),
+                                    )
+                                }
+                            }
+                        }
+                        ::core::result::Result::Err(rendered) => rendered,
+                    }
+                };
+                fields.push(("status", rendered));
+                let rendered = if async_debug_context.is_redacted("peers") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    {
+                        use ::async_debug::AsyncDebugTruncate as _;
+                        match async_debug_context.call(Mutex::lock(&self.peers)).await {
+                            ::core::result::Result::Ok(value) => {
+                                match async_debug_context.call(Remote::peers(&value)).await
+                                {
+                                    ::core::result::Result::Ok(value) => {
+                                        match value {
+                                            ::core::result::Result::Ok(ok) => {
+                                                ::async_debug::Rendered::ok(
+                                                    ok.async_debug_truncated(&async_debug_context),
+                                                )
+                                            }
+                                            ::core::result::Result::Err(error) => {
+                                                ::async_debug::Rendered::err(
+                                                    ::async_debug::Rendered::new(&error),
+                                                )
+                                            }
+                                        }
+                                    }
+                                    ::core::result::Result::Err(rendered) => rendered,
+                                }
+                            }
+                            ::core::result::Result::Err(rendered) => rendered,
+                        }
+                    }
+                };
+                fields.push(("peers", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_status, T_AsyncDebug_peers> {
+        pub(super) status: T_AsyncDebug_status,
+        pub(super) peers: T_AsyncDebug_peers,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_status: ::core::fmt::Debug,
+        T_AsyncDebug_peers: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_status, T_AsyncDebug_peers> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.status;
+            debug.field("status", value);
+            let value = &self.peers;
+            debug.field("peers", value);
+        }
+    }
+}
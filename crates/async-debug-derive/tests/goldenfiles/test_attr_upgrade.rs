@@ -0,0 +1,221 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<::async_debug::Upgraded<String>, ::async_debug::Rendered>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(
+        &self,
+    ) -> async_debug_input::Input<
+            ::async_debug::Upgraded<String>,
+            ::async_debug::Rendered,
+        > {
+        let async_debug_context = ::async_debug::AsyncDebugContext::new(self);
+        async_debug_input::Input {
+            parent: match ::async_debug::AsyncDebugUpgrade::async_debug_upgrade(
+                &self.parent,
+            ) {
+                ::core::option::Option::Some(strong) => {
+                    ::async_debug::Upgraded::Alive({
+                        let value = RwLock::read(&(*strong)).await;
+                        Parent::name(&value)
+                    })
+                }
+                ::core::option::Option::None => ::async_debug::Upgraded::Dropped,
+            },
+            sibling: {
+                use ::async_debug::AsyncDebugNested as _;
+                let nested_context = async_debug_context.nested();
+                if nested_context.is_past_max_depth() {
+                    ::async_debug::Rendered::elided()
+                } else {
+                    match &self.sibling {
+                        ::core::option::Option::Some(some) => {
+                            ::async_debug::Rendered::some(
+                                match ::async_debug::AsyncDebugUpgrade::async_debug_upgrade(
+                                    &(*some),
+                                ) {
+                                    ::core::option::Option::Some(strong) => {
+                                        let target = ::async_debug::async_call_target(
+                                            Mutex::lock,
+                                            &(*strong),
+                                        );
+                                        match nested_context.enter(target) {
+                                            ::core::result::Result::Ok(nested_context) => {
+                                                let value = Mutex::lock(target).await;
+                                                value.async_debug_nested(&nested_context).await
+                                            }
+                                            ::core::result::Result::Err(cycle) => cycle,
+                                        }
+                                    }
+                                    ::core::option::Option::None => {
+                                        ::async_debug::Rendered::dropped()
+                                    }
+                                },
+                            )
+                        }
+                        ::core::option::Option::None => ::async_debug::Rendered::none(),
+                    }
+                }
+            },
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<::async_debug::Upgraded<String>, ::async_debug::Rendered>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["parent", "sibling"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("parent") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    match ::async_debug::AsyncDebugUpgrade::async_debug_upgrade(
+                        &self.parent,
+                    ) {
+                        ::core::option::Option::Some(strong) => {
+                            match async_debug_context
+                                .call(RwLock::read(&(*strong)))
+                                .await
+                            {
+                                ::core::result::Result::Ok(value) => {
+                                    ::async_debug::Rendered::new(&Parent::name(&value))
+                                }
+                                ::core::result::Result::Err(rendered) => rendered,
+                            }
+                        }
+                        ::core::option::Option::None => {
+                            ::async_debug::Rendered::dropped()
+                        }
+                    }
+                };
+                fields.push(("parent", rendered));
+                let rendered = if async_debug_context.is_redacted("sibling") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    {
+                        use ::async_debug::AsyncDebugNested as _;
+                        let nested_context = async_debug_context.nested();
+                        if nested_context.is_past_max_depth() {
+                            ::async_debug::Rendered::elided()
+                        } else {
+                            match &self.sibling {
+                                ::core::option::Option::Some(some) => {
+                                    ::async_debug::Rendered::some(
+                                        match ::async_debug::AsyncDebugUpgrade::async_debug_upgrade(
+                                            &(*some),
+                                        ) {
+                                            ::core::option::Option::Some(strong) => {
+                                                let target = ::async_debug::async_call_target(
+                                                    Mutex::lock,
+                                                    &(*strong),
+                                                );
+                                                match nested_context.enter(target) {
+                                                    ::core::result::Result::Ok(nested_context) => {
+                                                        match async_debug_context.call(Mutex::lock(target)).await {
+                                                            ::core::result::Result::Ok(value) => {
+                                                                value.async_debug_nested(&nested_context).await
+                                                            }
+                                                            ::core::result::Result::Err(rendered) => rendered,
+                                                        }
+                                                    }
+                                                    ::core::result::Result::Err(cycle) => cycle,
+                                                }
+                                            }
+                                            ::core::option::Option::None => {
+                                                ::async_debug::Rendered::dropped()
+                                            }
+                                        },
+                                    )
+                                }
+                                ::core::option::Option::None => {
+                                    ::async_debug::Rendered::none()
+                                }
+                            }
+                        }
+                    }
+                };
+                fields.push(("sibling", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_parent, T_AsyncDebug_sibling> {
+        pub(super) parent: T_AsyncDebug_parent,
+        pub(super) sibling: T_AsyncDebug_sibling,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_parent: ::core::fmt::Debug,
+        T_AsyncDebug_sibling: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_parent, T_AsyncDebug_sibling> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.parent;
+            debug.field("parent", value);
+            let value = &self.sibling;
+            debug.field("sibling", value);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug u64, String>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<&u64, String> {
+        match self {
+            Self::Start { id: self_id, .. } => {
+                async_debug_input::Input::Start {
+                    id: &self_id,
+                }
+            }
+            Self::Auth(..) => async_debug_input::Input::Auth(),
+            Self::Tick { .. } => async_debug_input::Input::Tick,
+            Self::Message(self_0) => {
+                async_debug_input::Input::Message(RwLock::read(&self_0).await.clone())
+            }
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            match self {
+                Self::Start { id: self_id, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("id") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_id)
+                    };
+                    fields.push(("id", rendered));
+                    ::async_debug::Rendered::debug_struct("Begin", &fields)
+                }
+                Self::Auth(..) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    ::async_debug::Rendered::debug_tuple_non_exhaustive("Auth", &fields)
+                }
+                Self::Tick { .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    ::async_debug::Rendered::debug_struct("Tick", &fields)
+                }
+                Self::Message(self_0) => {
+                    if async_debug_context.is_redacted("0") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        match async_debug_context.call(RwLock::read(&self_0)).await {
+                            ::core::result::Result::Ok(value) => {
+                                ::async_debug::Rendered::new(&value.clone())
+                            }
+                            ::core::result::Result::Err(rendered) => rendered,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub enum Input<T_AsyncDebug_0_Start_id, T_AsyncDebug_3_Message_0> {
+        Start { id: T_AsyncDebug_0_Start_id },
+        Auth(),
+        Tick,
+        Message(T_AsyncDebug_3_Message_0),
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_0_Start_id: ::core::fmt::Debug,
+        T_AsyncDebug_3_Message_0: ::core::fmt::Debug,
+    > ::core::fmt::Debug for Input<T_AsyncDebug_0_Start_id, T_AsyncDebug_3_Message_0> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::Start { id: self_id } => {
+                    let mut debug = f.debug_struct("Begin");
+                    let value = &self_id;
+                    debug.field("id", value);
+                    debug.finish()
+                }
+                Self::Auth { .. } => {
+                    let mut debug = f.debug_tuple("Auth");
+                    debug.finish_non_exhaustive()
+                }
+                Self::Tick { .. } => {
+                    let mut debug = f.debug_struct("Tick");
+                    debug.finish()
+                }
+                Self::Message(self_0) => ::core::fmt::Debug::fmt(&self_0, f),
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
 #![rustfmt::skip]
 
-impl AsyncDebug for Input {}
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug String>;
+}
 #[automatically_derived]
 impl Input {
     async fn async_debug(&self) -> async_debug_input::Input<&String> {
@@ -9,8 +14,8 @@ impl Input {
         }
     }
 }
+#[allow(non_snake_case)]
 mod async_debug_input {
-    use super::*;
     #[allow(dead_code)]
     #[allow(non_camel_case_types)]
     #[automatically_derived]
@@ -0,0 +1,135 @@
+#![rustfmt::skip]
+
+impl<'a, T: Debug, const N: usize> ::async_debug::AsyncDebug for Input<'a, T, N>
+where
+    for<'x> &'x T: Debug,
+    T: Clone,
+{
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug &'a str, [T; N]>;
+}
+type InputSnapshot<'async_debug, 'a, T = u8, const N: usize = 4> = <Input<
+    'a,
+    T,
+    N,
+> as ::async_debug::AsyncDebug>::Snapshot<'async_debug>;
+#[automatically_derived]
+impl<'a, T: Debug, const N: usize> Input<'a, T, N>
+where
+    for<'x> &'x T: Debug,
+    T: Clone,
+{
+    async fn async_debug(&self) -> async_debug_input::Input<&&'a str, [T; N]> {
+        async_debug_input::Input {
+            name: &self.name,
+            values: self.values.clone(),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl<'a, T: Debug, const N: usize> ::async_debug::AsyncDebugNested for Input<'a, T, N>
+where
+    for<'x> &'x T: Debug,
+    T: Clone,
+{
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl<'a, T: Debug, const N: usize> ::async_debug::AsyncDebugFlatten for Input<'a, T, N>
+where
+    for<'x> &'x T: Debug,
+    T: Clone,
+{
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug &'a str, [T; N]>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["name", "values"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("name") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.name)
+                };
+                fields.push(("name", rendered));
+                let rendered = if async_debug_context.is_redacted("values") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.values.clone())
+                };
+                fields.push(("values", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_name, T_AsyncDebug_values> {
+        pub(super) name: T_AsyncDebug_name,
+        pub(super) values: T_AsyncDebug_values,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_name: ::core::fmt::Debug,
+        T_AsyncDebug_values: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_name, T_AsyncDebug_values> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.name;
+            debug.field("name", value);
+            let value = &self.values;
+            debug.field("values", value);
+        }
+    }
+}
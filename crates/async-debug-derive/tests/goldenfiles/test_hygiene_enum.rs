@@ -0,0 +1,118 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<
+        &'async_debug u64,
+        u64,
+        &'async_debug u64,
+        &'async_debug u64,
+    >;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<&u64, u64, &u64, &u64> {
+        match self {
+            Self::A_b { c: self_c, .. } => {
+                async_debug_input::Input::A_b {
+                    c: &self_c,
+                }
+            }
+            Self::A { b_c: self_b_c, lock: self_lock, .. } => {
+                async_debug_input::Input::A {
+                    b_c: lock(&self_b_c).await.clone(),
+                    lock: &self_lock,
+                }
+            }
+            Self::B(_, self_1) => async_debug_input::Input::B(&self_1),
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            match self {
+                Self::A_b { c: self_c, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("c") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_c)
+                    };
+                    fields.push(("c", rendered));
+                    ::async_debug::Rendered::debug_struct("A_b", &fields)
+                }
+                Self::A { b_c: self_b_c, lock: self_lock, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("b_c") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        match async_debug_context.call(lock(&self_b_c)).await {
+                            ::core::result::Result::Ok(value) => {
+                                ::async_debug::Rendered::new(&value.clone())
+                            }
+                            ::core::result::Result::Err(rendered) => rendered,
+                        }
+                    };
+                    fields.push(("b_c", rendered));
+                    let rendered = if async_debug_context.is_redacted("lock") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_lock)
+                    };
+                    fields.push(("lock", rendered));
+                    ::async_debug::Rendered::debug_struct("A", &fields)
+                }
+                Self::B(_, self_1) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("1") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        ::async_debug::Rendered::new(&self_1)
+                    };
+                    fields.push(rendered);
+                    ::async_debug::Rendered::debug_tuple("B", &fields)
+                }
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub enum Input<
+        T_AsyncDebug_0_A_b_c,
+        T_AsyncDebug_1_A_b_c,
+        T_AsyncDebug_1_A_lock,
+        T_AsyncDebug_2_B_1,
+    > {
+        A_b { c: T_AsyncDebug_0_A_b_c },
+        A { b_c: T_AsyncDebug_1_A_b_c, lock: T_AsyncDebug_1_A_lock },
+        B(T_AsyncDebug_2_B_1),
+    }
+}
@@ -0,0 +1,102 @@
+#![rustfmt::skip]
+
+impl<'async_debug> ::async_debug::AsyncDebug for Foo_Bar<'async_debug> {
+    type Snapshot<'async_debug_>
+    where
+        Self: 'async_debug_,
+    = async_debug__Foo_Bar::Foo_Bar<&'async_debug_ &'async_debug str>;
+}
+#[automatically_derived]
+impl<'async_debug> Foo_Bar<'async_debug> {
+    async fn async_debug(&self) -> async_debug__Foo_Bar::Foo_Bar<&&'async_debug str> {
+        async_debug__Foo_Bar::Foo_Bar {
+            test: &self.test,
+        }
+    }
+    async fn async_debug_with<'async_debug_>(
+        &'async_debug_ self,
+        options: &'async_debug_ ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl<'async_debug> ::async_debug::AsyncDebugNested for Foo_Bar<'async_debug> {
+    fn async_debug_nested<'async_debug_>(
+        &'async_debug_ self,
+        async_debug_context: &'async_debug_ ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug_ {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Foo_Bar")
+        }
+    }
+}
+#[automatically_derived]
+impl<'async_debug> ::async_debug::AsyncDebugFlatten for Foo_Bar<'async_debug> {
+    type Snapshot<'async_debug_>
+    where
+        Self: 'async_debug_,
+    = async_debug__Foo_Bar::Foo_Bar<&'async_debug_ &'async_debug str>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["test"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug_>(
+        &'async_debug_ self,
+        async_debug_context: &'async_debug_ ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug_ {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("test") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    ::async_debug::Rendered::new(&self.test)
+                };
+                fields.push(("test", rendered));
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug__Foo_Bar {
+    #[derive(::core::fmt::Debug)]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Foo_Bar<T_AsyncDebug_test> {
+        pub(super) test: T_AsyncDebug_test,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<T_AsyncDebug_test: ::core::fmt::Debug> ::async_debug::DebugFields
+    for Foo_Bar<T_AsyncDebug_test> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.test;
+            debug.field("test", value);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug u64, u64, &'async_debug u64>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<&u64, u64, &u64> {
+        match self {
+            Self::Start { id: self_id, .. } => {
+                async_debug_input::Input::Start {
+                    id: &self_id,
+                }
+            }
+            Self::Stop(self_0) => {
+                async_debug_input::Input::Stop(*Mutex::lock(&self_0).await)
+            }
+            Self::Pause { since: self_since, .. } => {
+                async_debug_input::Input::Pause {
+                    since: &self_since,
+                }
+            }
+            Self::Secret(..) => async_debug_input::Input::Secret(),
+            Self::Closed { .. } => async_debug_input::Input::Closed,
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            match self {
+                Self::Start { id: self_id, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("id") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        {
+                            use ::async_debug::{
+                                SerializedDebug as _, SerializedDeref as _,
+                                SerializedValue as _,
+                            };
+                            (&&&::async_debug::Serialized(&self_id))
+                                .async_debug_serialized()
+                        }
+                    };
+                    fields.push(("id", rendered));
+                    ::async_debug::Rendered::debug_struct("Start", &fields)
+                }
+                Self::Stop(self_0) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("0") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        match async_debug_context.call(Mutex::lock(&self_0)).await {
+                            ::core::result::Result::Ok(value) => {
+                                use ::async_debug::{
+                                    SerializedDebug as _, SerializedDeref as _,
+                                    SerializedValue as _,
+                                };
+                                (&&&::async_debug::Serialized(&*value))
+                                    .async_debug_serialized()
+                            }
+                            ::core::result::Result::Err(rendered) => rendered,
+                        }
+                    };
+                    fields.push(rendered);
+                    ::async_debug::Rendered::debug_tuple("Stop", &fields)
+                }
+                Self::Pause { since: self_since, .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    let rendered = if async_debug_context.is_redacted("since") {
+                        ::async_debug::Rendered::redacted()
+                    } else {
+                        {
+                            use ::async_debug::{
+                                SerializedDebug as _, SerializedDeref as _,
+                                SerializedValue as _,
+                            };
+                            (&&&::async_debug::Serialized(&self_since))
+                                .async_debug_serialized()
+                        }
+                    };
+                    fields.push(("since", rendered));
+                    ::async_debug::Rendered::debug_struct("Paused", &fields)
+                }
+                Self::Secret(..) => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<::async_debug::Rendered> = ::std::vec::Vec::new();
+                    ::async_debug::Rendered::debug_tuple_non_exhaustive(
+                        "Secret",
+                        &fields,
+                    )
+                }
+                Self::Closed { .. } => {
+                    #[allow(unused_mut)]
+                    let mut fields: ::std::vec::Vec<(&str, ::async_debug::Rendered)> = ::std::vec::Vec::new();
+                    ::async_debug::Rendered::debug_struct("Gone", &fields)
+                }
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::async_debug::serde::Serialize)]
+    #[serde(crate = "::async_debug::serde")]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub enum Input<
+        T_AsyncDebug_0_Start_id,
+        T_AsyncDebug_1_Stop_0,
+        T_AsyncDebug_2_Pause_since,
+    > {
+        Start { id: T_AsyncDebug_0_Start_id },
+        Stop(T_AsyncDebug_1_Stop_0),
+        #[serde(rename = "Paused")]
+        Pause { since: T_AsyncDebug_2_Pause_since },
+        Secret(),
+        #[serde(rename = "Gone")]
+        Closed,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_0_Start_id: ::core::fmt::Debug,
+        T_AsyncDebug_1_Stop_0: ::core::fmt::Debug,
+        T_AsyncDebug_2_Pause_since: ::core::fmt::Debug,
+    > ::core::fmt::Debug
+    for Input<
+        T_AsyncDebug_0_Start_id,
+        T_AsyncDebug_1_Stop_0,
+        T_AsyncDebug_2_Pause_since,
+    > {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::Start { id: self_id } => {
+                    let mut debug = f.debug_struct("Start");
+                    let value = &self_id;
+                    debug.field("id", value);
+                    debug.finish()
+                }
+                Self::Stop(self_0) => {
+                    let mut debug = f.debug_tuple("Stop");
+                    let value = &self_0;
+                    debug.field(value);
+                    debug.finish()
+                }
+                Self::Pause { since: self_since } => {
+                    let mut debug = f.debug_struct("Paused");
+                    let value = &self_since;
+                    debug.field("since", value);
+                    debug.finish()
+                }
+                Self::Secret { .. } => {
+                    let mut debug = f.debug_tuple("Secret");
+                    debug.finish_non_exhaustive()
+                }
+                Self::Closed { .. } => {
+                    let mut debug = f.debug_struct("Gone");
+                    debug.finish()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+#![rustfmt::skip]
+
+impl ::async_debug::AsyncDebug for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug u64, &'async_debug Vec<u64>>;
+}
+#[automatically_derived]
+impl Input {
+    async fn async_debug(&self) -> async_debug_input::Input<&u64, &Vec<u64>> {
+        async_debug_input::Input {
+            rw_lock: &self.rw_lock,
+            items: if Vec::is_empty(&self.items) {
+                ::core::option::Option::None
+            } else {
+                ::core::option::Option::Some(&self.items)
+            },
+        }
+    }
+    async fn async_debug_with<'async_debug>(
+        &'async_debug self,
+        options: &'async_debug ::async_debug::AsyncDebugOptions,
+    ) -> ::async_debug::Rendered {
+        let async_debug_context = <::async_debug::AsyncDebugContext as ::core::default::Default>::default()
+            .with_options(options);
+        ::async_debug::AsyncDebugNested::async_debug_nested(self, &async_debug_context)
+            .await
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugNested for Input {
+    fn async_debug_nested<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<Output = ::async_debug::Rendered> + 'async_debug {
+        async move {
+            let async_debug_context = match async_debug_context.enter(self) {
+                ::core::result::Result::Ok(async_debug_context) => async_debug_context,
+                ::core::result::Result::Err(cycle) => return cycle,
+            };
+            ::async_debug::AsyncDebugFlatten::async_debug_flatten(
+                    self,
+                    &async_debug_context,
+                )
+                .await
+                .debug_struct("Input")
+        }
+    }
+}
+#[automatically_derived]
+impl ::async_debug::AsyncDebugFlatten for Input {
+    type Snapshot<'async_debug>
+    where
+        Self: 'async_debug,
+    = async_debug_input::Input<&'async_debug u64, &'async_debug Vec<u64>>;
+    const FIELD_NAMES: ::async_debug::FieldNames<'static> = ::async_debug::FieldNames {
+        names: &["rw_lock", "items"],
+        flattened: &[],
+    };
+    fn async_debug_flatten_snapshot(
+        &self,
+    ) -> impl ::core::future::Future<Output = Self::Snapshot<'_>> {
+        self.async_debug()
+    }
+    fn async_debug_flatten<'async_debug>(
+        &'async_debug self,
+        async_debug_context: &'async_debug ::async_debug::AsyncDebugContext,
+    ) -> impl ::core::future::Future<
+            Output = ::async_debug::RenderedFields,
+        > + 'async_debug {
+        async move {
+            {
+                #[allow(unused_mut)]
+                let mut fields: ::std::vec::Vec<
+                    (&'static str, ::async_debug::Rendered),
+                > = ::std::vec::Vec::new();
+                let rendered = if async_debug_context.is_redacted("rw_lock") {
+                    ::async_debug::Rendered::redacted()
+                } else {
+                    {
+                        use ::async_debug::{
+                            SerializedDebug as _, SerializedDeref as _,
+                            SerializedValue as _,
+                        };
+                        (&&&::async_debug::Serialized(&self.rw_lock))
+                            .async_debug_serialized()
+                    }
+                };
+                fields.push(("rw_lock", rendered));
+                if let ::core::option::Option::Some(rendered)
+                    = if async_debug_context.is_redacted("items") {
+                        ::core::option::Option::Some(::async_debug::Rendered::redacted())
+                    } else {
+                        if Vec::is_empty(&self.items) {
+                            ::core::option::Option::None
+                        } else {
+                            ::core::option::Option::Some({
+                                use ::async_debug::{
+                                    SerializedDebug as _, SerializedDeref as _,
+                                    SerializedValue as _,
+                                };
+                                (&&&::async_debug::Serialized(&self.items))
+                                    .async_debug_serialized()
+                            })
+                        }
+                    } {
+                    fields.push(("items", rendered));
+                }
+                ::async_debug::RenderedFields::new(fields, false)
+            }
+        }
+    }
+}
+#[allow(non_snake_case)]
+mod async_debug_input {
+    #[derive(::async_debug::serde::Serialize)]
+    #[serde(crate = "::async_debug::serde")]
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    pub struct Input<T_AsyncDebug_rw_lock, T_AsyncDebug_items> {
+        #[serde(rename = "lock")]
+        pub(super) rw_lock: T_AsyncDebug_rw_lock,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(super) items: ::core::option::Option<T_AsyncDebug_items>,
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_rw_lock: ::core::fmt::Debug,
+        T_AsyncDebug_items: ::core::fmt::Debug,
+    > ::core::fmt::Debug for Input<T_AsyncDebug_rw_lock, T_AsyncDebug_items> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            {
+                let mut debug = f.debug_struct("Input");
+                ::async_debug::DebugFields::debug_fields(self, &mut debug);
+                debug.finish()
+            }
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[automatically_derived]
+    impl<
+        T_AsyncDebug_rw_lock: ::core::fmt::Debug,
+        T_AsyncDebug_items: ::core::fmt::Debug,
+    > ::async_debug::DebugFields for Input<T_AsyncDebug_rw_lock, T_AsyncDebug_items> {
+        fn debug_fields(&self, debug: &mut ::core::fmt::DebugStruct<'_, '_>) {
+            let value = &self.rw_lock;
+            debug.field("rw_lock", value);
+            if let ::core::option::Option::Some(value) = &self.items {
+                debug.field("items", value);
+            }
+        }
+    }
+}
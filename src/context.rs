@@ -0,0 +1,188 @@
+use std::{
+    any::type_name,
+    borrow::Cow,
+    future::{poll_fn, Future},
+    pin::pin,
+    sync::Arc,
+    task::{Poll, Waker},
+    time::Instant,
+};
+
+use crate::{
+    timer::{Timer, TimerEntry},
+    AsyncDebugOptions, Rendered,
+};
+
+/// State of a single `async_debug()` traversal, passed down to nested fields
+#[derive(Clone, Debug, Default)]
+pub struct AsyncDebugContext {
+    depth: usize,
+    max_depth: Option<usize>,
+    max_items: Option<usize>,
+    max_len: Option<usize>,
+    /// When to stop waiting for `async_call`s
+    deadline: Option<Instant>,
+    /// Whether to never wait for `async_call`s
+    try_lock: bool,
+    /// Names of the fields to render as `<redacted>`
+    redacted: Arc<[Cow<'static, str>]>,
+    /// Name of the profile fields are rendered with
+    profile: Option<Cow<'static, str>>,
+    /// Address and type name of every value being rendered, from the root down
+    ancestors: Vec<(usize, &'static str)>,
+}
+
+impl AsyncDebugContext {
+    /// Context for the traversal rooted at `root`, without any limits
+    pub fn new<T: ?Sized>(root: &T) -> Self {
+        Self {
+            ancestors: vec![Self::ancestor(root)],
+            ..Self::default()
+        }
+    }
+
+    /// Render nested fields more than `max_depth` levels below the current value as `..`.
+    ///
+    /// Like the other limits this only ever tightens an existing limit.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Self::tighten(self.max_depth, self.depth.saturating_add(max_depth));
+        self
+    }
+
+    /// Render at most `max_items` items of a list, set or map, followed by `.. N more`
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Self::tighten(self.max_items, max_items);
+        self
+    }
+
+    /// Render at most `max_len` characters of a string, followed by `.. N more`
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Self::tighten(self.max_len, max_len);
+        self
+    }
+
+    /// Apply the `options` of an `async_debug_with()` call, replacing any limits already set
+    pub fn with_options(mut self, options: &AsyncDebugOptions) -> Self {
+        if let Some(max_depth) = options.max_depth {
+            self.max_depth = Some(self.depth.saturating_add(max_depth));
+        }
+        self.max_items = options.max_items.or(self.max_items);
+        self.max_len = options.max_len.or(self.max_len);
+        self.deadline = options
+            .timeout
+            .and_then(|timeout| Instant::now().checked_add(timeout));
+        self.try_lock = options.try_lock;
+        self.redacted = options.redacted.clone().into();
+        self.profile = options.profile.clone();
+        self
+    }
+
+    fn tighten(limit: Option<usize>, value: usize) -> Option<usize> {
+        Some(limit.map_or(value, |limit| limit.min(value)))
+    }
+
+    /// Number of nested fields between the root and the current value
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Maximum number of items of a list, set or map to render
+    pub fn max_items(&self) -> Option<usize> {
+        self.max_items
+    }
+
+    /// Maximum number of characters of a string to render
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Name of the profile fields are rendered with, if any
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Whether the field named `field` is rendered as `<redacted>`
+    pub fn is_redacted(&self, field: &str) -> bool {
+        self.redacted.iter().any(|redacted| redacted == field)
+    }
+
+    /// Await `future`, the `async_call` of a field, or render `<locked>` when it is not ready and
+    /// the traversal never waits, or `<timed out>` when it is not ready by the deadline.
+    ///
+    /// Futures are only polled from within the traversal, the task is woken at the deadline by a
+    /// single timer thread shared by all traversals so this works on any executor.
+    pub async fn call<F: Future>(&self, future: F) -> Result<F::Output, Rendered> {
+        let mut future = pin!(future);
+        let mut timer: Option<(Waker, Option<TimerEntry>)> = None;
+
+        poll_fn(|cx| {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                return Poll::Ready(Ok(output));
+            }
+
+            if self.try_lock {
+                return Poll::Ready(Err(Rendered::verbatim("<locked>".into())));
+            }
+
+            if let Some(deadline) = self.deadline {
+                if Instant::now() >= deadline {
+                    return Poll::Ready(Err(Rendered::verbatim("<timed out>".into())));
+                }
+
+                if !matches!(&timer, Some((waker, _)) if waker.will_wake(cx.waker())) {
+                    let waker = cx.waker().clone();
+                    timer = Some((waker.clone(), Timer::register(deadline, waker)));
+                }
+            }
+
+            Poll::Pending
+        })
+        .await
+    }
+
+    /// Context for a nested field of the current value
+    pub fn nested(&self) -> Self {
+        Self {
+            depth: self.depth + 1,
+            ..self.clone()
+        }
+    }
+
+    /// Whether the current value is past the maximum depth and should not be rendered
+    pub fn is_past_max_depth(&self) -> bool {
+        matches!(self.max_depth, Some(max_depth) if self.depth > max_depth)
+    }
+
+    /// Context for the fields of `value`, or a `<cycle: Type@0x..>` placeholder when `value` is
+    /// already being rendered further up the traversal, e.g. through an `Arc` back-reference.
+    ///
+    /// Values are identified by address and type, a struct and its first field share an address.
+    pub fn enter<T: ?Sized>(&self, value: &T) -> Result<Self, Rendered> {
+        let ancestor = Self::ancestor(value);
+
+        if self.ancestors.contains(&ancestor) {
+            return Err(Rendered::verbatim(format!(
+                "<cycle: {}@{:p}>",
+                Self::short_type_name::<T>(),
+                value as *const T as *const (),
+            )));
+        }
+
+        let mut context = self.clone();
+        context.ancestors.push(ancestor);
+
+        Ok(context)
+    }
+
+    fn ancestor<T: ?Sized>(value: &T) -> (usize, &'static str) {
+        (value as *const T as *const () as usize, type_name::<T>())
+    }
+
+    /// `Pool` for `my_crate::pool::Pool<Connection>`
+    fn short_type_name<T: ?Sized>() -> &'static str {
+        let name = type_name::<T>();
+        let name = &name[..name.find('<').unwrap_or(name.len())];
+
+        name.rsplit("::").next().unwrap_or(name)
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    fmt::{self, Debug, Formatter},
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::Rendered;
+
+/// A container whose items an `each(...)` step of an `async_call` pipeline is awaited on, e.g.
+/// `#[async_debug(async_call = [Mutex::lock, each(RwLock::read)], clone, ty = Vec<u64>)]`.
+pub trait AsyncDebugEach {
+    /// Type of the items, the values of a map
+    type Item: ?Sized;
+
+    /// Every item of `self` in order, a map keeps the rendering of their keys
+    fn async_debug_each(&self) -> Each<&Self::Item>;
+}
+
+macro_rules! impl_deref {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: AsyncDebugEach + ?Sized> AsyncDebugEach for $ty {
+                type Item = T::Item;
+
+                fn async_debug_each(&self) -> Each<&Self::Item> {
+                    (**self).async_debug_each()
+                }
+            }
+        )*
+    };
+}
+
+impl_deref!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
+
+macro_rules! impl_list {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T> AsyncDebugEach for $ty {
+                type Item = T;
+
+                fn async_debug_each(&self) -> Each<&Self::Item> {
+                    Each::list(self.iter().collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_list!([T], Vec<T>, VecDeque<T>, BTreeSet<T>);
+
+impl<T, const N: usize> AsyncDebugEach for [T; N] {
+    type Item = T;
+
+    fn async_debug_each(&self) -> Each<&Self::Item> {
+        Each::list(self.iter().collect())
+    }
+}
+
+impl<T, S> AsyncDebugEach for HashSet<T, S> {
+    type Item = T;
+
+    fn async_debug_each(&self) -> Each<&Self::Item> {
+        Each::list(self.iter().collect())
+    }
+}
+
+impl<K: Debug, V> AsyncDebugEach for BTreeMap<K, V> {
+    type Item = V;
+
+    fn async_debug_each(&self) -> Each<&Self::Item> {
+        Each::map(
+            self.iter()
+                .map(|(key, value)| (Rendered::new(key), value))
+                .collect(),
+        )
+    }
+}
+
+impl<K: Debug, V, S> AsyncDebugEach for HashMap<K, V, S> {
+    type Item = V;
+
+    fn async_debug_each(&self) -> Each<&Self::Item> {
+        Each::map(
+            self.iter()
+                .map(|(key, value)| (Rendered::new(key), value))
+                .collect(),
+        )
+    }
+}
+
+/// Snapshot of the items an `each(...)` step was awaited on, rendered as a list, or as a map
+/// keyed by the rendering of their keys.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Each<T> {
+    keys: Option<Vec<Rendered>>,
+    items: Vec<T>,
+}
+
+impl<T> Each<T> {
+    /// `items` of a list or set, in order
+    pub fn list(items: Vec<T>) -> Self {
+        Self { keys: None, items }
+    }
+
+    /// `entries` of a map, in order
+    pub fn map(entries: Vec<(Rendered, T)>) -> Self {
+        let (keys, items) = entries.into_iter().unzip();
+
+        Self {
+            keys: Some(keys),
+            items,
+        }
+    }
+
+    /// The items, in order
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// The keys of a map, in the order of `items`
+    pub fn keys(&self) -> Option<&[Rendered]> {
+        self.keys.as_deref()
+    }
+
+    /// The keys of a map and the items, to be replaced with what the rest of the pipeline
+    /// awaited on them by `from_parts`
+    #[doc(hidden)]
+    pub fn into_parts(self) -> (Option<Vec<Rendered>>, Vec<T>) {
+        (self.keys, self.items)
+    }
+
+    #[doc(hidden)]
+    pub fn from_parts(keys: Option<Vec<Rendered>>, items: Vec<T>) -> Self {
+        Self { keys, items }
+    }
+}
+
+impl<T: Debug> Debug for Each<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.keys {
+            Some(keys) => f.debug_map().entries(keys.iter().zip(&self.items)).finish(),
+            None => f.debug_list().entries(&self.items).finish(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: crate::serde::Serialize> crate::serde::Serialize for Each<T> {
+    fn serialize<S: crate::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.keys {
+            Some(keys) => serializer.collect_map(keys.iter().zip(&self.items)),
+            None => serializer.collect_seq(&self.items),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    fmt::{self, Debug, DebugStruct, Formatter},
+    future::Future,
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{AsyncDebugContext, Rendered};
+
+/// Render the fields of a struct as `flatten`ed fields of another struct deriving `AsyncDebug`.
+///
+/// Derived for every struct with named fields whose generated struct derives `Debug`, unless it
+/// is `transparent`.
+pub trait AsyncDebugFlatten {
+    /// The generated struct of `Self`, held by that of the struct it is flattened into, whose
+    /// `Debug` writes its fields in place and which is serialized with `#[serde(flatten)]`
+    type Snapshot<'a>
+    where
+        Self: 'a;
+
+    /// Names of the fields rendered by `async_debug_flatten`, checked against those of the struct
+    /// it is flattened into when that is compiled
+    #[doc(hidden)]
+    const FIELD_NAMES: FieldNames<'static>;
+
+    /// The generated struct of `self`, as returned by its `async_debug()`
+    fn async_debug_flatten_snapshot(&self) -> impl Future<Output = Self::Snapshot<'_>>;
+
+    /// Render every field of `self`, `ctx` tracks the traversal of the struct it is flattened into
+    fn async_debug_flatten<'a>(
+        &'a self,
+        ctx: &'a AsyncDebugContext,
+    ) -> impl Future<Output = RenderedFields> + 'a;
+}
+
+macro_rules! impl_deref {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: AsyncDebugFlatten + ?Sized> AsyncDebugFlatten for $ty {
+                type Snapshot<'a> = T::Snapshot<'a> where Self: 'a;
+
+                const FIELD_NAMES: FieldNames<'static> = T::FIELD_NAMES;
+
+                fn async_debug_flatten_snapshot(
+                    &self,
+                ) -> impl Future<Output = Self::Snapshot<'_>> {
+                    (**self).async_debug_flatten_snapshot()
+                }
+
+                fn async_debug_flatten<'a>(
+                    &'a self,
+                    ctx: &'a AsyncDebugContext,
+                ) -> impl Future<Output = RenderedFields> + 'a {
+                    (**self).async_debug_flatten(ctx)
+                }
+            }
+        )*
+    };
+}
+
+impl_deref!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
+
+/// Owned rendering of the fields of a `flatten`ed struct, written into the struct it is flattened
+/// into, or serialized as a map.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct RenderedFields {
+    fields: Vec<(&'static str, Rendered)>,
+    non_exhaustive: bool,
+}
+
+impl RenderedFields {
+    /// `fields` of a struct, some of whose fields were left out if `non_exhaustive`
+    pub fn new(fields: Vec<(&'static str, Rendered)>, non_exhaustive: bool) -> Self {
+        Self {
+            fields,
+            non_exhaustive,
+        }
+    }
+
+    /// Placeholder for a flattened field redacted with `AsyncDebugOptions::with_redacted`, a single
+    /// `<redacted>` field under its own name
+    pub fn redacted(name: &'static str) -> Self {
+        Self::from(vec![(name, Rendered::redacted())])
+    }
+
+    /// Name and rendering of every field, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Rendered)> {
+        self.fields.iter().map(|(name, value)| (*name, value))
+    }
+
+    /// Whether some fields of the struct were left out, so it is rendered followed by `..`
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.non_exhaustive
+    }
+
+    /// Render the struct named `name` with these fields, as `#[derive(Debug)]` would
+    pub fn debug_struct(&self, name: &str) -> Rendered {
+        if self.non_exhaustive {
+            Rendered::debug_struct_non_exhaustive(name, &self.fields)
+        } else {
+            Rendered::debug_struct(name, &self.fields)
+        }
+    }
+}
+
+impl From<Vec<(&'static str, Rendered)>> for RenderedFields {
+    fn from(fields: Vec<(&'static str, Rendered)>) -> Self {
+        Self::new(fields, false)
+    }
+}
+
+impl IntoIterator for RenderedFields {
+    type Item = (&'static str, Rendered);
+    type IntoIter = std::vec::IntoIter<(&'static str, Rendered)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+impl Debug for RenderedFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::serde::Serialize for RenderedFields {
+    fn serialize<S: crate::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Writes the fields of a generated struct to the `Debug` of the generated struct it is flattened
+/// into, in place of the field holding it
+#[doc(hidden)]
+pub trait DebugFields {
+    fn debug_fields(&self, debug: &mut DebugStruct<'_, '_>);
+}
+
+/// Names of the fields of a struct, and of every struct flattened into it
+#[doc(hidden)]
+pub struct FieldNames<'a> {
+    pub names: &'a [&'a str],
+    pub flattened: &'a [&'a FieldNames<'a>],
+}
+
+impl<'a> FieldNames<'a> {
+    /// Whether any field is named `name`, at any depth
+    pub const fn contains(&self, name: &str) -> bool {
+        let mut index = 0;
+
+        while index < self.names.len() {
+            if str_eq(self.names[index], name) {
+                return true;
+            }
+
+            index += 1;
+        }
+
+        let mut index = 0;
+
+        while index < self.flattened.len() {
+            if self.flattened[index].contains(name) {
+                return true;
+            }
+
+            index += 1;
+        }
+
+        false
+    }
+
+    /// Whether any field is named like one of `other`, at any depth
+    pub const fn intersects(&self, other: &FieldNames<'_>) -> bool {
+        let mut index = 0;
+
+        while index < self.names.len() {
+            if other.contains(self.names[index]) {
+                return true;
+            }
+
+            index += 1;
+        }
+
+        let mut index = 0;
+
+        while index < self.flattened.len() {
+            if self.flattened[index].intersects(other) {
+                return true;
+            }
+
+            index += 1;
+        }
+
+        false
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut index = 0;
+
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    true
+}
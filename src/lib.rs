@@ -31,7 +31,7 @@
 //! Add to cargo.toml:
 //! ```toml
 //! [dependencies]
-//! async-debug = "0.1.3"
+//! async-debug = "0.2.0"
 //! ```
 //!
 //! ```rust
@@ -53,8 +53,836 @@
 //! );
 //! # }
 //! ```
+//!
+//! ## Nested and recursive types
+//! Fields marked `nested` are rendered through their own `AsyncDebug` implementation, also
+//! through containers such as `Vec`, `Option` and `Arc`, or behind a lock with `async_call`.
+//! Self-referential types are detected and their futures boxed, mark mutually recursive types
+//! with `recursive`. Past `max_depth` nested fields render as `..` without being locked, as do
+//! values already being rendered further up, e.g. through an `Arc` back-reference, as
+//! `<cycle: Type@0x..>`, and so do locks already held further up, e.g. `<cycle: Mutex@0x..>`,
+//! rather than being awaited again.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::RwLock;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(max_depth = 1)]
+//! struct Node {
+//!     name: String,
+//!     #[async_debug(async_call = RwLock::read, nested)]
+//!     children: RwLock<Vec<Node>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let leaf = Node { name: "leaf".into(), children: RwLock::default() };
+//! let node = Node { name: "node".into(), children: RwLock::from(vec![leaf]) };
+//! let root = Node { name: "root".into(), children: RwLock::from(vec![node]) };
+//! assert_eq!(
+//!     format!("{:?}", root.async_debug().await),
+//!     "Node { name: \"root\", children: [Node { name: \"node\", children: .. }] }",
+//! );
+//! # }
+//! ```
+//!
+//! ## Limits
+//! Fields marked `truncate` are rendered through [`AsyncDebugTruncate`], with at most `max_items`
+//! items of a list, set or map and `max_len` characters of a string, followed by `.. N more`.
+//! The rest is never cloned or formatted. Limits set on the struct or enum `async_debug()` is
+//! called on apply to the whole traversal, limits on a field tighten them for that field.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(max_items = 3)]
+//! struct Samples {
+//!     #[async_debug(truncate)]
+//!     values: Vec<u64>,
+//!     #[async_debug(max_len = 4)]
+//!     label: String,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let samples = Samples { values: (0..10_000).collect(), label: "temperature".into() };
+//! assert_eq!(
+//!     format!("{:?}", samples.async_debug().await),
+//!     "Samples { values: [0, 1, 2, .. 9997 more], label: \"temp\" .. 7 more }",
+//! );
+//! # }
+//! ```
+//!
+//! ## Runtime options
+//! `async_debug_with()` renders the same fields with [`AsyncDebugOptions`] chosen per call, e.g.
+//! briefly in a hot path without waiting on any lock and in full in an incident dump. Options can
+//! set a timeout for the whole call, never wait with try-lock, replace the limits of the struct
+//! or enum and redact fields by name. Fields whose `async_call` is not ready render as `<locked>`
+//! or `<timed out>`, redacted fields as `<redacted>`.
+//!
+//! ```rust
+//! use async_debug::{AsyncDebug, AsyncDebugOptions};
+//! use tokio::sync::RwLock;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Session {
+//!     user: String,
+//!     token: String,
+//!     #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>)]
+//!     requests: RwLock<Vec<u64>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let session = Session {
+//!     user: "admin".into(),
+//!     token: "secret".into(),
+//!     requests: RwLock::from(vec![1, 2, 3]),
+//! };
+//! let brief = AsyncDebugOptions::new().with_try_lock().with_redacted("token");
+//!
+//! let requests = session.requests.write().await;
+//! assert_eq!(
+//!     format!("{:?}", session.async_debug_with(&brief).await),
+//!     "Session { user: \"admin\", token: <redacted>, requests: <locked> }",
+//! );
+//! # drop(requests);
+//! # }
+//! ```
+//!
+//! Unlike `async_debug()`, `async_debug_with()` returns the output as [`Rendered`] rather than the
+//! generated struct or enum, so its fields can't be read from the result, only formatted. With the
+//! `serde` feature it serializes as the structure it was rendered from, see [Features](#features).
+//!
+//! ## Profiles
+//! Fields can render differently per profile, e.g. tersely for per-request logs and verbosely for
+//! diagnostic dumps. `profile(name, ...)` takes the same arguments as the field attribute itself
+//! and replaces them when rendering with that profile, selected with
+//! [`AsyncDebugOptions::with_profile`] or the generated `async_debug_{name}()` method. Profiles
+//! apply to nested fields too.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Request {
+//!     id: u64,
+//!     #[async_debug(profile(brief, skip))]
+//!     headers: Vec<(String, String)>,
+//!     #[async_debug(profile(brief, max_items = 2))]
+//!     chunks: Vec<u64>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let request = Request {
+//!     id: 1,
+//!     headers: vec![("host".into(), "localhost".into())],
+//!     chunks: vec![1, 2, 3],
+//! };
+//! assert_eq!(
+//!     format!("{:?}", request.async_debug_brief().await),
+//!     "Request { id: 1, chunks: [1, 2, .. 1 more] }",
+//! );
+//! # }
+//! ```
+//!
+//! Like `async_debug_with()`, the `async_debug_{name}()` methods return [`Rendered`] rather than
+//! the generated struct or enum, so fields can't be read from the result, and serialize as the
+//! structure they were rendered from with the `serde` feature.
+//!
+//! ## Skipping absent fields
+//! `skip_if = path` leaves a field out when the function returns true for a reference to its
+//! value, after any `async_call`, like serde's `skip_serializing_if`. `skip_if_empty` does the
+//! same for values whose `is_empty()` is true. Such fields are `Option`s in the generated
+//! struct/enum, `None` when left out.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::Mutex;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Queue {
+//!     name: String,
+//!     #[async_debug(skip_if = Option::is_none)]
+//!     parent: Option<String>,
+//!     #[async_debug(async_call = Mutex::lock, clone, ty = Vec<u64>, skip_if_empty)]
+//!     pending: Mutex<Vec<u64>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let queue = Queue {
+//!     name: "queue".into(),
+//!     parent: None,
+//!     pending: Mutex::default(),
+//! };
+//! assert_eq!(format!("{:?}", queue.async_debug().await), "Queue { name: \"queue\" }");
+//! # }
+//! ```
+//!
+//! ## Non-exhaustive output
+//! Skipped fields leave no trace in the output. With `#[async_debug(non_exhaustive)]` a struct or
+//! variant with skipped fields, or declared `#[non_exhaustive]` itself, is rendered followed by
+//! `..`, as `finish_non_exhaustive()` does, so a partial snapshot reads as such. Fields skipped in
+//! a profile only do so when rendered with that profile.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(non_exhaustive)]
+//! struct Service {
+//!     a: u64,
+//!     #[async_debug(skip)]
+//!     b: u64,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let service = Service { a: 1, b: 2 };
+//! assert_eq!(format!("{:?}", service.async_debug().await), "Service { a: 1, .. }");
+//! # }
+//! ```
+//!
+//! ## Flattening
+//! A `flatten`ed field renders the fields of its struct, itself deriving `AsyncDebug`, in place
+//! of the field, also in JSON. A field named like another one of the struct it is flattened into,
+//! at any depth, fails compilation.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Core {
+//!     id: u64,
+//! }
+//!
+//! #[derive(AsyncDebug)]
+//! struct Service {
+//!     name: String,
+//!     #[async_debug(flatten)]
+//!     core: Core,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let service = Service {
+//!     name: "service".into(),
+//!     core: Core { id: 1 },
+//! };
+//! assert_eq!(
+//!     format!("{:?}", service.async_debug().await),
+//!     "Service { name: \"service\", id: 1 }",
+//! );
+//! # }
+//! ```
+//!
+//! ## Transparent newtypes
+//! A newtype around a lock adds a layer to every snapshot containing it. With
+//! `#[async_debug(transparent)]` a struct with a single field, other than skipped ones, renders
+//! exactly as that field.
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::RwLock;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(transparent)]
+//! struct Shared(#[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>)] Arc<RwLock<Vec<u64>>>);
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let shared = Shared(Arc::new(RwLock::new(vec![1, 2])));
+//! assert_eq!(format!("{:?}", shared.async_debug().await), "[1, 2]");
+//! # }
+//! ```
+//!
+//! ## Enum variants
+//! Variants take their own attribute arguments: `rename = "..."` renders a variant under another
+//! name, `skip` renders it as `Variant(..)` without touching its fields, `name_only` as just its
+//! name, and `transparent` renders a variant with a single field exactly as that field.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//!
+//! struct Token(String);
+//!
+//! #[derive(AsyncDebug)]
+//! enum Event {
+//!     #[async_debug(rename = "Begin")]
+//!     Start { id: u64 },
+//!     #[async_debug(skip)]
+//!     Login(Token),
+//!     #[async_debug(name_only)]
+//!     Tick(u64),
+//!     #[async_debug(transparent)]
+//!     Message(String),
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let event = Event::Login(Token("secret".into()));
+//! assert_eq!(format!("{:?}", event.async_debug().await), "Login(..)");
+//! assert_eq!(format!("{:?}", Event::Tick(1).async_debug().await), "Tick");
+//! # }
+//! ```
+//!
+//! ## Chained async calls
+//! A lock often guards a collection of other locks. `async_call` takes a pipeline of functions,
+//! each awaited on the result of the one before, and a trailing `each(...)` awaits its own
+//! functions on every item of a list, set or map. The snapshot of the items is an `Each` of `ty`,
+//! and an item still locked renders as `<locked>` on its own.
+//!
+//! ```rust
+//! use std::{collections::BTreeMap, sync::Arc};
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::{Mutex, RwLock};
+//!
+//! #[derive(AsyncDebug)]
+//! struct Registry {
+//!     #[async_debug(async_call = [Mutex::lock, each(RwLock::read)], copy, ty = u64)]
+//!     counts: Mutex<BTreeMap<String, Arc<RwLock<u64>>>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let counts = BTreeMap::from([("a".to_string(), Arc::new(RwLock::new(1)))]);
+//! let registry = Registry { counts: Mutex::new(counts) };
+//! assert_eq!(
+//!     format!("{:?}", registry.async_debug().await),
+//!     "Registry { counts: {\"a\": 1} }",
+//! );
+//! # }
+//! ```
+//!
+//! ## Summaries
+//! Rather than cloning all of a locked value, `map = some_function` renders what the function
+//! returns for a reference to it, after any `async_call`. As its return type can not be inferred it
+//! is given with `ty`, unless rendered through `nested` or `truncate`.
+//!
+//! ```rust
+//! use std::collections::VecDeque;
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::Mutex;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Queue {
+//!     #[async_debug(async_call = Mutex::lock, map = VecDeque::len, ty = usize)]
+//!     pending: Mutex<VecDeque<String>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let queue = Queue {
+//!     pending: Mutex::new(VecDeque::from(vec!["a".to_string(), "b".to_string()])),
+//! };
+//! assert_eq!(format!("{:?}", queue.async_debug().await), "Queue { pending: 2 }");
+//! # }
+//! ```
+//!
+//! ## Conversions
+//! Besides `clone` and `copy`, the value, after any `async_call`, can be taken with `to_owned`, for
+//! unsized values such as `RwLock<str>`, with `to_string` through its `Display`, or converted with
+//! `into = Type` from an owned copy. `to_string` and `into` determine the type of the field, as does
+//! `to_owned` unless it follows an `async_call` or `map`, where it is given with `ty`.
+//!
+//! ```rust
+//! use std::path::{Path, PathBuf};
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::RwLock;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Config {
+//!     #[async_debug(async_call = RwLock::read, into = PathBuf)]
+//!     path: RwLock<Box<Path>>,
+//!     #[async_debug(to_string)]
+//!     port: u16,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let config = Config {
+//!     path: RwLock::new(Path::new("/etc/app.toml").into()),
+//!     port: 8080,
+//! };
+//! assert_eq!(
+//!     format!("{:?}", config.async_debug().await),
+//!     r#"Config { path: "/etc/app.toml", port: "8080" }"#,
+//! );
+//! # }
+//! ```
+//!
+//! ## Optional fields
+//! A field that may hold nothing, e.g. `Option<Arc<Mutex<Conn>>>`, is marked `optional`, so
+//! `async_call` and the rest of its arguments apply to the value within `Some`, and `None` is
+//! rendered as is. The field of the generated struct/enum is an `Option` of the type they give.
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::Mutex;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Pool {
+//!     #[async_debug(optional, async_call = Mutex::lock, copy, ty = u16)]
+//!     primary: Option<Arc<Mutex<u16>>>,
+//!     #[async_debug(optional, async_call = Mutex::lock, copy, ty = u16)]
+//!     replica: Option<Arc<Mutex<u16>>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let pool = Pool {
+//!     primary: Some(Arc::new(Mutex::new(5432))),
+//!     replica: None,
+//! };
+//! assert_eq!(
+//!     format!("{:?}", pool.async_debug().await),
+//!     "Pool { primary: Some(5432), replica: None }",
+//! );
+//! # }
+//! ```
+//!
+//! ## Weak references
+//! A back-pointer kept as a `std::sync::Weak` or `std::rc::Weak` is marked `upgrade`, so it is
+//! upgraded through `AsyncDebugUpgrade` and `async_call` and the rest of its arguments apply to the
+//! target, rendered as `<dropped>` once that is gone. The upgraded value can not be borrowed, so it
+//! is converted, e.g. with `clone`, or rendered through `nested` or `truncate`, and the field of the
+//! generated struct/enum is an `Upgraded` of that.
+//!
+//! ```rust
+//! use std::sync::{Arc, Weak};
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::RwLock;
+//!
+//! #[derive(AsyncDebug)]
+//! struct Child {
+//!     #[async_debug(upgrade, async_call = RwLock::read, clone, ty = String)]
+//!     parent: Weak<RwLock<String>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let parent = Arc::new(RwLock::new("root".to_string()));
+//! let child = Child {
+//!     parent: Arc::downgrade(&parent),
+//! };
+//! assert_eq!(format!("{:?}", child.async_debug().await), r#"Child { parent: "root" }"#);
+//!
+//! drop(parent);
+//! assert_eq!(format!("{:?}", child.async_debug().await), "Child { parent: <dropped> }");
+//! # }
+//! ```
+//!
+//! ## Fallible calls
+//! An `async_call` returning a `Result` is marked `try`. The field of the generated struct/enum is
+//! then a `Result` whose error is rendered as it is in `Err(..)`, so one failing call does not keep
+//! the rest from being shown. With `error = SomeError` on the struct/enum a `try_async_debug()`
+//! method is generated as well, returning the generated struct/enum with the unwrapped values, or
+//! the first error converted `From` it into `SomeError`. The unwrapped value can not be borrowed, so
+//! it is converted, e.g. with `clone`, or rendered through `nested` or `truncate`.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//!
+//! #[derive(Debug)]
+//! struct Unreachable;
+//!
+//! struct Remote {
+//!     reachable: bool,
+//! }
+//!
+//! impl Remote {
+//!     async fn status(&self) -> Result<String, Unreachable> {
+//!         if self.reachable {
+//!             Ok("up".to_string())
+//!         } else {
+//!             Err(Unreachable)
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(error = Unreachable)]
+//! struct Node {
+//!     #[async_debug(async_call = Remote::status, try, ty = String)]
+//!     remote: Remote,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let node = Node {
+//!     remote: Remote { reachable: true },
+//! };
+//! assert_eq!(node.try_async_debug().await.unwrap().remote, "up");
+//!
+//! let node = Node {
+//!     remote: Remote { reachable: false },
+//! };
+//! assert_eq!(
+//!     format!("{:?}", node.async_debug().await),
+//!     "Node { remote: Err(Unreachable) }"
+//! );
+//! assert!(node.try_async_debug().await.is_err());
+//! # }
+//! ```
+//!
+//! ## Computed fields
+//! Values that are not stored in the struct, like an uptime or the length of a queue behind a lock,
+//! are added with `field(name = "...", ...)` on the struct itself. Its value is that of `call`, or
+//! of `async_call` awaited, on a reference to `self`, converted by the rest of its arguments as
+//! that of any other field. Computed fields follow the stored ones, in the order they are declared,
+//! or the field named by `after`.
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::Mutex;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(field(name = "uptime", call = Worker::uptime, ty = Duration))]
+//! #[async_debug(field(name = "queue_depth", async_call = Worker::queue_depth, ty = usize, after = "name"))]
+//! struct Worker {
+//!     name: String,
+//!     #[async_debug(skip)]
+//!     queue: Mutex<Vec<String>>,
+//! }
+//!
+//! impl Worker {
+//!     fn uptime(&self) -> Duration {
+//!         Duration::from_secs(42)
+//!     }
+//!
+//!     async fn queue_depth(&self) -> usize {
+//!         self.queue.lock().await.len()
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let worker = Worker {
+//!     name: "worker".to_string(),
+//!     queue: Mutex::new(vec!["job".to_string()]),
+//! };
+//! assert_eq!(
+//!     format!("{:?}", worker.async_debug().await),
+//!     r#"Worker { name: "worker", queue_depth: 1, uptime: 42s }"#,
+//! );
+//! # }
+//! ```
+//!
+//! ## Reading snapshots
+//! The fields of the generated struct are private to the module declaring the struct, so elsewhere
+//! a snapshot can only be formatted. They are given another visibility with `field_vis = ...`, or
+//! read through methods of the same name generated with `getters`, as visible as the struct itself.
+//!
+//! ```rust
+//! mod worker {
+//!     use async_debug::AsyncDebug;
+//!     use tokio::sync::Mutex;
+//!
+//!     #[derive(AsyncDebug)]
+//!     #[async_debug(field_vis = pub, getters)]
+//!     pub struct Worker {
+//!         #[async_debug(async_call = Mutex::lock, clone, ty = Vec<String>)]
+//!         pub queue: Mutex<Vec<String>>,
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let worker = worker::Worker {
+//!     queue: tokio::sync::Mutex::new(vec!["job".to_string()]),
+//! };
+//! let snapshot = worker.async_debug().await;
+//! assert_eq!(snapshot.queue.len(), 1);
+//! assert_eq!(snapshot.queue().len(), 1);
+//! # }
+//! ```
+//!
+//! ## Naming snapshots
+//! The generated struct or enum lives in a module named after the struct or enum, e.g.
+//! `async_debug_worker` for `Worker`, or as given with `module = ...`, and has a generic parameter
+//! for the type of each field. `snapshot = ...` adds a type alias for it, generic over just a
+//! lifetime and the generic parameters of the struct or enum, as visible as the struct or enum or
+//! as given with `snapshot_vis = ...`. Generic code names it through `AsyncDebug::Snapshot`.
+//!
+//! ```rust
+//! mod worker {
+//!     use async_debug::AsyncDebug;
+//!     use tokio::sync::Mutex;
+//!
+//!     #[derive(AsyncDebug)]
+//!     #[async_debug(snapshot = WorkerSnapshot, module = worker_snapshot)]
+//!     pub struct Worker {
+//!         pub name: String,
+//!         #[async_debug(async_call = Mutex::lock, clone, ty = Vec<String>)]
+//!         pub queue: Mutex<Vec<String>>,
+//!     }
+//! }
+//!
+//! use async_debug::AsyncDebug;
+//! use worker::{Worker, WorkerSnapshot};
+//!
+//! struct Report<'a> {
+//!     worker: WorkerSnapshot<'a>,
+//! }
+//!
+//! fn describe<T: AsyncDebug>(snapshot: &T::Snapshot<'_>) -> String
+//! where
+//!     for<'a> T::Snapshot<'a>: std::fmt::Debug,
+//! {
+//!     format!("{:?}", snapshot)
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let worker = Worker {
+//!     name: "worker".to_string(),
+//!     queue: tokio::sync::Mutex::new(vec!["job".to_string()]),
+//! };
+//! let report = Report {
+//!     worker: worker.async_debug().await,
+//! };
+//! assert_eq!(
+//!     describe::<Worker>(&report.worker),
+//!     r#"Worker { name: "worker", queue: ["job"] }"#,
+//! );
+//! # }
+//! ```
+//!
+//! ## Re-exported crate
+//! The generated code refers to `::async_debug`, which fails to resolve when the derive is used
+//! through a crate re-exporting `async_debug` without depending on it directly.
+//! `#[async_debug(crate = "...")]` gives the path to use instead.
+//!
+//! ```rust
+//! # pub extern crate async_debug;
+//! mod facade {
+//!     pub use async_debug;
+//! }
+//!
+//! use facade::async_debug::AsyncDebug;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(crate = "crate::facade::async_debug")]
+//! struct Config {
+//!     name: String,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let config = Config {
+//!     name: "config".to_string(),
+//! };
+//! assert_eq!(
+//!     format!("{:?}", config.async_debug().await),
+//!     r#"Config { name: "config" }"#,
+//! );
+//! # }
+//! ```
+//!
+//! ## Custom bounds
+//! The implementation of a generic struct or enum requires each field type to be renderable, e.g.
+//! `Vec<Tree<T>>: AsyncDebugNested` for a nested field, which a recursive type can never satisfy as
+//! the requirement refers back to itself. `#[async_debug(bound = "...")]` replaces the inferred
+//! bounds with the given ones.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(bound = "T: std::fmt::Debug + Send + Sync")]
+//! struct Tree<T> {
+//!     value: T,
+//!     #[async_debug(nested)]
+//!     children: Vec<Tree<T>>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let tree = Tree {
+//!     value: 1,
+//!     children: vec![Tree { value: 2, children: Vec::new() }],
+//! };
+//! assert_eq!(
+//!     format!("{:?}", tree.async_debug().await),
+//!     "Tree { value: 1, children: [Tree { value: 2, children: [] }] }",
+//! );
+//! # }
+//! ```
+//!
+//! ## Send and local futures
+//! The future returned by `async_debug()` is `Send` whenever the fields allow it, which is only
+//! noticed where it is spawned. `#[async_debug(send)]` requires it, asserting that every field type
+//! is `Sync` so that a field breaking it is reported at the field. Recursive types box their
+//! futures as `Send`, `#[async_debug(local)]` boxes them without requiring it instead, for fields
+//! such as `Rc`.
+//!
+//! ```rust
+//! use std::rc::Rc;
+//!
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::Mutex;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(send)]
+//! struct Shared {
+//!     #[async_debug(async_call = Mutex::lock, clone, ty = Vec<u64>)]
+//!     values: Mutex<Vec<u64>>,
+//! }
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(local)]
+//! struct Node {
+//!     name: Rc<str>,
+//!     #[async_debug(nested)]
+//!     children: Vec<Node>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let shared = Shared { values: Mutex::new(vec![1, 2]) };
+//! let rendered = tokio::spawn(async move { format!("{:?}", shared.async_debug().await) })
+//!     .await
+//!     .unwrap();
+//! assert_eq!(rendered, "Shared { values: [1, 2] }");
+//!
+//! let node = Node { name: "root".into(), children: Vec::new() };
+//! assert_eq!(
+//!     format!("{:?}", node.async_debug().await),
+//!     "Node { name: \"root\", children: [] }",
+//! );
+//! # }
+//! ```
+//!
+//! ## Derives and attributes
+//! Snapshots implement `Debug`, `#[async_debug(derive(...))]` derives further traits for them, e.g.
+//! to keep one and compare it with a later one, and `attr(...)` adds attributes to the generated
+//! struct or enum, or, on a field, to its field. `Debug` itself can only be derived this way with
+//! `disable_derive_debug`.
+//!
+//! ```rust
+//! use async_debug::AsyncDebug;
+//! use tokio::sync::Mutex;
+//!
+//! #[derive(AsyncDebug)]
+//! #[async_debug(derive(Clone, PartialEq), attr(doc = "Snapshot of a counter"))]
+//! struct Counter {
+//!     #[async_debug(async_call = Mutex::lock, copy, ty = u64, attr(doc = "Current count"))]
+//!     count: Mutex<u64>,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let counter = Counter { count: Mutex::new(1) };
+//! let before = counter.async_debug().await;
+//! assert_eq!(before.clone(), counter.async_debug().await);
+//!
+//! *counter.count.lock().await += 1;
+//! assert_ne!(before, counter.async_debug().await);
+//! # }
+//! ```
+//!
+//! ## Features
+//! - `serde`: the generated `async_debug_*` structs and enums also derive `serde::Serialize`,
+//!   so the result of `async_debug()` can be serialized, e.g. to JSON. Skipped fields are
+//!   not serialized, field attributes can be passed through with `attr(serde(...))`. Variants
+//!   are serialized under the name they are rendered as, a `skip` variant without its fields
+//!   and a `name_only` one as just its name.
+//!
+//!   `nested` and `truncate`d fields, and the result of `async_debug_with()`, serialize as the
+//!   structure they were rendered from: structs as maps of their fields, tuple structs as
+//!   sequences, lists and maps as such, `Option`s as the value or `null`. Values serialize with
+//!   their own `Serialize` implementation where the derive knows of one, or that of the value a
+//!   lock guard derefs to. Values of a generic type, keys of nested maps and placeholders such
+//!   as `<redacted>` or `<cycle: ..>` serialize as strings, their `Debug` output. To serialize
+//!   with fields redacted, serialize the result of `async_debug_with()`, the snapshot of
+//!   `async_debug()` is never redacted.
+//!
+//!   A dropped `upgrade` field serializes as `null` in the snapshot of `async_debug()`, like
+//!   `None`, and as `"<dropped>"` in the result of `async_debug_with()`.
+//!
+//! ## Minimum supported Rust version
+//! Rust 1.83 or later is required. Since 0.2.0 the traits use `impl Future` and generic
+//! associated types, and `AsyncDebug` names the generated struct or enum as its `Snapshot`, a
+//! breaking change from 0.1: a manual `impl AsyncDebug for MyStruct {}` must now declare it, or
+//! derive `AsyncDebug` instead.
+mod context;
+mod each;
+mod flatten;
+mod nested;
+mod options;
+mod rendered;
+mod structure;
+mod timer;
+mod truncate;
+mod upgrade;
+
+use std::{future::Future, pin::Pin};
+
 pub use async_debug_derive::AsyncDebug;
 
-/// `AsyncDebug` trait, this just marks the struct or enum as having AsyncDebug capabilities,
-/// the actual implementation is in an inherent impl
-pub trait AsyncDebug {}
+pub use self::{
+    context::AsyncDebugContext,
+    each::{AsyncDebugEach, Each},
+    flatten::{AsyncDebugFlatten, RenderedFields},
+    nested::AsyncDebugNested,
+    options::AsyncDebugOptions,
+    rendered::Rendered,
+    truncate::AsyncDebugTruncate,
+    upgrade::{AsyncDebugUpgrade, Upgraded},
+};
+
+#[doc(hidden)]
+pub use self::flatten::{DebugFields, FieldNames};
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use self::structure::{
+    AsyncDebugTruncateSerialize, Serialized, SerializedDebug, SerializedDeref, SerializedTruncated,
+    SerializedTruncatedDebug, SerializedTruncatedDeref, SerializedTruncatedValue, SerializedValue,
+};
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde_crate as serde;
+
+/// `AsyncDebug` trait, this marks the struct or enum as having AsyncDebug capabilities and names
+/// the generated struct or enum, the actual implementation is in an inherent impl
+pub trait AsyncDebug {
+    /// The type returned by `async_debug()`, borrowing from `Self` for `'a`
+    type Snapshot<'a>
+    where
+        Self: 'a;
+}
+
+/// Asserts a field type is `Sync` in `send` implementations, so errors point at the field
+#[doc(hidden)]
+pub fn assert_field_is_sync<T: ?Sized + Sync>() {}
+
+/// `arg` as the `async_call` of a `nested` field is applied to it, after any deref coercion, so
+/// it can be checked for cycles before the call is awaited
+#[doc(hidden)]
+pub fn async_call_target<'a, A: ?Sized, F>(
+    _async_call: impl FnOnce(&'a A) -> F,
+    arg: &'a A,
+) -> &'a A {
+    arg
+}
+
+/// Boxed `Send` future, as returned by `recursive` implementations of [`AsyncDebugNested`]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Boxed future, as returned by `recursive` and `local` implementations of [`AsyncDebugNested`]
+pub type LocalBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
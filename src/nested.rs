@@ -0,0 +1,121 @@
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fmt::Debug,
+    future::Future,
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{AsyncDebugContext, Rendered};
+
+/// Render a value as a `nested` field of a struct or enum deriving `AsyncDebug`.
+///
+/// Derived for every struct or enum whose generated struct or enum derives `Debug`, implemented
+/// here for the common containers of those.
+pub trait AsyncDebugNested {
+    /// Render `self` to an owned value, `ctx` tracks the traversal the field is rendered in
+    fn async_debug_nested<'a>(
+        &'a self,
+        ctx: &'a AsyncDebugContext,
+    ) -> impl Future<Output = Rendered> + 'a;
+}
+
+macro_rules! impl_deref {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: AsyncDebugNested + ?Sized> AsyncDebugNested for $ty {
+                fn async_debug_nested<'a>(
+                    &'a self,
+                    ctx: &'a AsyncDebugContext,
+                ) -> impl Future<Output = Rendered> + 'a {
+                    (**self).async_debug_nested(ctx)
+                }
+            }
+        )*
+    };
+}
+
+impl_deref!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
+
+impl<T: AsyncDebugNested> AsyncDebugNested for Option<T> {
+    async fn async_debug_nested<'a>(&'a self, ctx: &'a AsyncDebugContext) -> Rendered {
+        match self {
+            Some(value) => Rendered::some(value.async_debug_nested(ctx).await),
+            None => Rendered::none(),
+        }
+    }
+}
+
+/// Render every item in order, as a list of at most `max_items`
+async fn render_list<'a, T: AsyncDebugNested + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    ctx: &'a AsyncDebugContext,
+) -> Rendered {
+    let mut items = items.into_iter();
+    let mut rendered = Vec::new();
+
+    for item in items.by_ref().take(ctx.max_items().unwrap_or(usize::MAX)) {
+        rendered.push(item.async_debug_nested(ctx).await);
+    }
+
+    Rendered::list(&rendered, items.count())
+}
+
+macro_rules! impl_list {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: AsyncDebugNested> AsyncDebugNested for $ty {
+                fn async_debug_nested<'a>(
+                    &'a self,
+                    ctx: &'a AsyncDebugContext,
+                ) -> impl Future<Output = Rendered> + 'a {
+                    render_list(self, ctx)
+                }
+            }
+        )*
+    };
+}
+
+impl_list!([T], Vec<T>, VecDeque<T>);
+
+impl<T: AsyncDebugNested, const N: usize> AsyncDebugNested for [T; N] {
+    fn async_debug_nested<'a>(
+        &'a self,
+        ctx: &'a AsyncDebugContext,
+    ) -> impl Future<Output = Rendered> + 'a {
+        render_list(self, ctx)
+    }
+}
+
+/// Render every value in order, as a map of at most `max_items` keyed by the `Debug` of its key
+async fn render_map<'a, K: Debug + 'a, V: AsyncDebugNested + 'a>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+    ctx: &'a AsyncDebugContext,
+) -> Rendered {
+    let mut entries = entries.into_iter();
+    let mut rendered = Vec::new();
+
+    for (key, value) in entries.by_ref().take(ctx.max_items().unwrap_or(usize::MAX)) {
+        rendered.push((Rendered::new(key), value.async_debug_nested(ctx).await));
+    }
+
+    Rendered::map(&rendered, entries.count())
+}
+
+impl<K: Debug, V: AsyncDebugNested> AsyncDebugNested for BTreeMap<K, V> {
+    fn async_debug_nested<'a>(
+        &'a self,
+        ctx: &'a AsyncDebugContext,
+    ) -> impl Future<Output = Rendered> + 'a {
+        render_map(self, ctx)
+    }
+}
+
+impl<K: Debug, V: AsyncDebugNested, S> AsyncDebugNested for HashMap<K, V, S> {
+    fn async_debug_nested<'a>(
+        &'a self,
+        ctx: &'a AsyncDebugContext,
+    ) -> impl Future<Output = Rendered> + 'a {
+        render_map(self, ctx)
+    }
+}
@@ -0,0 +1,70 @@
+use std::{borrow::Cow, time::Duration};
+
+/// Options of a single `async_debug_with()` call, so the same value can be rendered briefly in a
+/// hot path and in full in an incident dump.
+///
+/// Limits set here replace those set on the struct or enum, limits set on a field still tighten
+/// them for that field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AsyncDebugOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) try_lock: bool,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) max_items: Option<usize>,
+    pub(crate) max_len: Option<usize>,
+    pub(crate) redacted: Vec<Cow<'static, str>>,
+    pub(crate) profile: Option<Cow<'static, str>>,
+}
+
+impl AsyncDebugOptions {
+    /// Wait for every `async_call` and render without any limits beyond those of the struct or enum
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop waiting for `async_call`s `timeout` after `async_debug_with()` was called, those not
+    /// ready by then render as `<timed out>`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Never wait for an `async_call`, one not ready when first polled, e.g. a lock held
+    /// elsewhere, renders as `<locked>`
+    pub fn with_try_lock(mut self) -> Self {
+        self.try_lock = true;
+        self
+    }
+
+    /// Render nested fields more than `max_depth` levels below the root as `..`
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Render at most `max_items` items of a list, set or map, followed by `.. N more`
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Render at most `max_len` characters of a string, followed by `.. N more`
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Render every field named `field`, at any depth, as `<redacted>` without calling its
+    /// `async_call`. Fields of tuple structs and variants are named by their index, e.g. `"0"`.
+    pub fn with_redacted(mut self, field: impl Into<Cow<'static, str>>) -> Self {
+        self.redacted.push(field.into());
+        self
+    }
+
+    /// Render fields with the arguments of their `profile(name, ...)` attribute, at any depth.
+    /// Fields without one for `name` render as they otherwise would.
+    pub fn with_profile(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.profile = Some(name.into());
+        self
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use crate::{structure::Structure, Each};
+
+/// Owned rendering of a nested value, so it can outlive any lock it was rendered under.
+///
+/// Both the plain and the alternate (`{:#?}`) output are kept, whichever the formatter asks for is
+/// written out verbatim. With the `serde` feature it serializes as the structure of the value it
+/// was rendered from, see the crate documentation.
+#[derive(Clone)]
+pub struct Rendered {
+    plain: String,
+    alternate: String,
+    #[cfg(feature = "serde")]
+    structure: Structure,
+}
+
+impl Rendered {
+    /// Render `value` with its `Debug` implementation
+    pub fn new<T: Debug + ?Sized>(value: &T) -> Self {
+        let plain = format!("{:?}", value);
+
+        Self {
+            alternate: format!("{:#?}", value),
+            #[cfg(feature = "serde")]
+            structure: Structure::Str(plain.clone()),
+            plain,
+        }
+    }
+
+    /// Placeholder for a value that was not rendered, e.g. past the maximum depth
+    pub fn elided() -> Self {
+        Self::verbatim("..".into())
+    }
+
+    /// Placeholder for the value of a field redacted with `AsyncDebugOptions::with_redacted`
+    pub fn redacted() -> Self {
+        Self::verbatim("<redacted>".into())
+    }
+
+    /// Placeholder for the target of an `upgrade` field that has been dropped
+    pub fn dropped() -> Self {
+        Self::verbatim("<dropped>".into())
+    }
+
+    /// `text` as is, in both the plain and alternate output
+    pub(crate) fn verbatim(text: String) -> Self {
+        Self {
+            alternate: text.clone(),
+            #[cfg(feature = "serde")]
+            structure: Structure::Str(text.clone()),
+            plain: text,
+        }
+    }
+
+    /// Replace what this serializes to with `structure`
+    #[cfg(feature = "serde")]
+    pub(crate) fn with_structure(mut self, structure: impl FnOnce() -> Structure) -> Self {
+        self.structure = structure();
+        self
+    }
+
+    /// Without the `serde` feature there is nothing to serialize, `structure` is never evaluated
+    #[cfg(not(feature = "serde"))]
+    pub(crate) fn with_structure(self, _structure: impl FnOnce() -> Structure) -> Self {
+        self
+    }
+
+    /// What this serializes to, a copy for the rendering of a value containing it
+    #[cfg(feature = "serde")]
+    fn structure(&self) -> Structure {
+        self.structure.clone()
+    }
+
+    #[cfg(not(feature = "serde"))]
+    fn structure(&self) -> Structure {
+        Structure::Null
+    }
+
+    /// Render `items` as a list, followed by `.. N more` if `remaining` items were left out
+    pub(crate) fn list(items: &[Rendered], remaining: usize) -> Self {
+        Self::new(&List(items, remaining))
+            .with_structure(|| Structure::Seq(items.iter().map(Self::structure).collect()))
+    }
+
+    /// Render `items` as a set, followed by `.. N more` if `remaining` items were left out
+    pub(crate) fn set(items: &[Rendered], remaining: usize) -> Self {
+        Self::new(&Set(items, remaining))
+            .with_structure(|| Structure::Seq(items.iter().map(Self::structure).collect()))
+    }
+
+    /// Render `entries` as a map, followed by `.. N more` if `remaining` entries were left out
+    pub(crate) fn map(entries: &[(Rendered, Rendered)], remaining: usize) -> Self {
+        Self::new(&Map(entries, remaining)).with_structure(|| {
+            Structure::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.structure(), value.structure()))
+                    .collect(),
+            )
+        })
+    }
+
+    /// Render `value` as `Some(value)`
+    #[doc(hidden)]
+    pub fn some(value: Rendered) -> Self {
+        Self::new(&Some(&value)).with_structure(|| value.structure())
+    }
+
+    /// Render `None`
+    #[doc(hidden)]
+    pub fn none() -> Self {
+        Self::new(&None::<()>).with_structure(|| Structure::Null)
+    }
+
+    /// Render `value` as `Ok(value)`
+    #[doc(hidden)]
+    pub fn ok(value: Rendered) -> Self {
+        Self::new(&Ok::<_, ()>(&value))
+            .with_structure(|| Structure::Variant("Ok", Box::new(value.structure())))
+    }
+
+    /// Render `error` as `Err(error)`
+    #[doc(hidden)]
+    pub fn err(error: Rendered) -> Self {
+        Self::new(&Err::<(), _>(&error))
+            .with_structure(|| Structure::Variant("Err", Box::new(error.structure())))
+    }
+
+    /// Render the items an `each(...)` step was awaited on, as a list or a map
+    #[doc(hidden)]
+    pub fn each(each: &Each<Rendered>) -> Self {
+        Self::new(each).with_structure(|| match each.keys() {
+            Some(keys) => Structure::Map(
+                keys.iter()
+                    .zip(each.items())
+                    .map(|(key, value)| (key.structure(), value.structure()))
+                    .collect(),
+            ),
+            None => Structure::Seq(each.items().iter().map(Self::structure).collect()),
+        })
+    }
+
+    /// Render `value` as a string of at most `max_len` characters, followed by `.. N more`
+    pub(crate) fn str(value: &str, max_len: Option<usize>) -> Self {
+        match max_len.and_then(|max_len| value.char_indices().nth(max_len)) {
+            Some((index, _)) => {
+                let more = value[index..].chars().count();
+
+                Self::verbatim(format!("{:?} .. {} more", &value[..index], more)).with_structure(
+                    || Structure::Str(format!("{} .. {} more", &value[..index], more)),
+                )
+            }
+            None => Self::new(value).with_structure(|| Structure::Str(value.into())),
+        }
+    }
+
+    /// Render a struct or struct variant named `name` with `fields`, as `#[derive(Debug)]` would
+    pub fn debug_struct(name: &str, fields: &[(&str, Rendered)]) -> Self {
+        Self::new(&Struct(name, fields, false))
+            .with_structure(|| Self::fields_structure(name, fields))
+    }
+
+    /// Render a struct or struct variant named `name` with `fields`, followed by `..` as some of
+    /// its fields were left out
+    pub fn debug_struct_non_exhaustive(name: &str, fields: &[(&str, Rendered)]) -> Self {
+        Self::new(&Struct(name, fields, true))
+            .with_structure(|| Self::fields_structure(name, fields))
+    }
+
+    /// Serialized as a map of `fields`, or as `name` without any like a unit variant
+    fn fields_structure(name: &str, fields: &[(&str, Rendered)]) -> Structure {
+        if fields.is_empty() {
+            return Structure::Str(name.into());
+        }
+
+        Structure::Struct(
+            fields
+                .iter()
+                .map(|(name, value)| ((*name).into(), value.structure()))
+                .collect(),
+        )
+    }
+
+    /// Render a tuple struct or tuple variant named `name` with `fields`, as `#[derive(Debug)]`
+    /// would
+    pub fn debug_tuple(name: &str, fields: &[Rendered]) -> Self {
+        Self::new(&Tuple(name, fields, false))
+            .with_structure(|| Self::tuple_structure(name, fields))
+    }
+
+    /// Render a tuple struct or tuple variant named `name` with `fields`, followed by `..` as
+    /// some of its fields were left out
+    pub fn debug_tuple_non_exhaustive(name: &str, fields: &[Rendered]) -> Self {
+        Self::new(&Tuple(name, fields, true)).with_structure(|| Self::tuple_structure(name, fields))
+    }
+
+    /// Serialized as a sequence of `fields`, as just the field of a newtype, or as `name` without
+    /// any like a unit variant
+    fn tuple_structure(name: &str, fields: &[Rendered]) -> Structure {
+        match fields {
+            [] => Structure::Str(name.into()),
+            [field] => field.structure(),
+            fields => Structure::Seq(fields.iter().map(Self::structure).collect()),
+        }
+    }
+
+    /// The plain `{:?}` rendering
+    pub fn as_str(&self) -> &str {
+        &self.plain
+    }
+}
+
+/// Compared by their output, what they serialize to follows from the same value
+impl PartialEq for Rendered {
+    fn eq(&self, other: &Self) -> bool {
+        self.plain == other.plain && self.alternate == other.alternate
+    }
+}
+
+impl Eq for Rendered {}
+
+impl Hash for Rendered {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.plain.hash(state);
+        self.alternate.hash(state);
+    }
+}
+
+impl Debug for Rendered {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(&self.alternate)
+        } else {
+            f.write_str(&self.plain)
+        }
+    }
+}
+
+/// Struct named `.0` with fields `.1`, non-exhaustive if `.2`
+struct Struct<'a>(&'a str, &'a [(&'a str, Rendered)], bool);
+
+impl<'a> Debug for Struct<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct(self.0);
+
+        for (name, value) in self.1 {
+            debug_struct.field(name, value);
+        }
+
+        if self.2 {
+            debug_struct.finish_non_exhaustive()
+        } else {
+            debug_struct.finish()
+        }
+    }
+}
+
+/// Tuple named `.0` with fields `.1`, non-exhaustive if `.2`
+struct Tuple<'a>(&'a str, &'a [Rendered], bool);
+
+impl<'a> Debug for Tuple<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple(self.0);
+
+        for value in self.1 {
+            debug_tuple.field(value);
+        }
+
+        if self.2 {
+            debug_tuple.finish_non_exhaustive()
+        } else {
+            debug_tuple.finish()
+        }
+    }
+}
+
+/// `.. N more` entry of a truncated list or map
+struct More(usize);
+
+impl Debug for More {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, ".. {} more", self.0)
+    }
+}
+
+struct List<'a, T>(&'a [T], usize);
+
+impl<'a, T: Debug> Debug for List<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        list.entries(self.0);
+
+        if self.1 > 0 {
+            list.entry(&More(self.1));
+        }
+
+        list.finish()
+    }
+}
+
+struct Set<'a, T>(&'a [T], usize);
+
+impl<'a, T: Debug> Debug for Set<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut set = f.debug_set();
+        set.entries(self.0);
+
+        if self.1 > 0 {
+            set.entry(&More(self.1));
+        }
+
+        set.finish()
+    }
+}
+
+/// `key: value` entry of a map, written as a set so it can end in `.. N more`
+struct Entry<'a, K, V>(&'a K, &'a V);
+
+impl<'a, K: Debug, V: Debug> Debug for Entry<'a, K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)?;
+        f.write_str(": ")?;
+        self.1.fmt(f)
+    }
+}
+
+struct Map<'a, K, V>(&'a [(K, V)], usize);
+
+impl<'a, K: Debug, V: Debug> Debug for Map<'a, K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut set = f.debug_set();
+        set.entries(self.0.iter().map(|(key, value)| Entry(key, value)));
+
+        if self.1 > 0 {
+            set.entry(&More(self.1));
+        }
+
+        set.finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::serde::Serialize for Rendered {
+    fn serialize<S: crate::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.structure.serialize(serializer)
+    }
+}
@@ -0,0 +1,326 @@
+/// What a `Rendered` value serializes to with the `serde` feature, the structure of the value it
+/// was rendered from rather than its `Debug` output.
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+pub(crate) enum Structure {
+    /// A string, the `Debug` output of a value that could not be serialized, or a placeholder
+    /// such as `<redacted>`
+    Str(String),
+    /// A value serialized with its own `Serialize` implementation
+    #[cfg(feature = "serde")]
+    Value(serde_json::Value),
+    /// `None`
+    Null,
+    /// Fields of a struct or struct variant, in order
+    Struct(Vec<(String, Structure)>),
+    /// Items of a list or set, fields of a tuple struct or tuple variant
+    Seq(Vec<Structure>),
+    /// Entries of a map
+    Map(Vec<(Structure, Structure)>),
+    /// `Ok` or `Err` of a `Result`, externally tagged like `serde` does
+    Variant(&'static str, Box<Structure>),
+}
+
+#[cfg(feature = "serde")]
+pub use self::serde::{
+    AsyncDebugTruncateSerialize, Serialized, SerializedDebug, SerializedDeref, SerializedTruncated,
+    SerializedTruncatedDebug, SerializedTruncatedDeref, SerializedTruncatedValue, SerializedValue,
+};
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::{
+        collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+        fmt::Debug,
+        ops::Deref,
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use super::Structure;
+    use crate::{
+        serde::{ser::SerializeMap, Serialize, Serializer},
+        AsyncDebugContext, Rendered,
+    };
+
+    impl Structure {
+        /// Structure of `value` as serialized by its `Serialize` implementation, or of its `Debug`
+        /// output if that fails
+        pub(crate) fn serialized<T: Debug + Serialize + ?Sized>(value: &T) -> Self {
+            serde_json::to_value(value)
+                .map_or_else(|_| Self::Str(format!("{:?}", value)), Self::Value)
+        }
+    }
+
+    impl Serialize for Structure {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Str(value) => serializer.serialize_str(value),
+                Self::Value(value) => value.serialize(serializer),
+                Self::Null => serializer.serialize_none(),
+                Self::Struct(fields) => serializer.collect_map(fields.iter().map(|(k, v)| (k, v))),
+                Self::Seq(items) => serializer.collect_seq(items),
+                Self::Map(entries) => serializer.collect_map(entries.iter().map(|(k, v)| (k, v))),
+                Self::Variant(variant, value) => {
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry(variant, value)?;
+                    map.end()
+                }
+            }
+        }
+    }
+
+    /// A value rendered by the derive, which serializes it with its `Serialize` implementation
+    /// where one is known, either of the value itself or of the value it derefs to, e.g. within a
+    /// lock guard. Any other value serializes as its `Debug` output, e.g. that of a generic type.
+    ///
+    /// The implementation is chosen by method resolution on `&&&Serialized(value)`, trying
+    /// `SerializedValue`, `SerializedDeref` and then `SerializedDebug`.
+    #[doc(hidden)]
+    pub struct Serialized<'a, T: ?Sized>(pub &'a T);
+
+    #[doc(hidden)]
+    pub trait SerializedValue {
+        fn async_debug_serialized(&self) -> Rendered;
+    }
+
+    impl<T: Debug + Serialize + ?Sized> SerializedValue for &&Serialized<'_, T> {
+        fn async_debug_serialized(&self) -> Rendered {
+            Rendered::new(self.0).with_structure(|| Structure::serialized(self.0))
+        }
+    }
+
+    #[doc(hidden)]
+    pub trait SerializedDeref {
+        fn async_debug_serialized(&self) -> Rendered;
+    }
+
+    impl<T: Debug + Deref + ?Sized> SerializedDeref for &Serialized<'_, T>
+    where
+        T::Target: Debug + Serialize,
+    {
+        fn async_debug_serialized(&self) -> Rendered {
+            Rendered::new(self.0).with_structure(|| Structure::serialized(&**self.0))
+        }
+    }
+
+    #[doc(hidden)]
+    pub trait SerializedDebug {
+        fn async_debug_serialized(&self) -> Rendered;
+    }
+
+    impl<T: Debug + ?Sized> SerializedDebug for Serialized<'_, T> {
+        fn async_debug_serialized(&self) -> Rendered {
+            Rendered::new(self.0)
+        }
+    }
+
+    /// A `truncate`d value rendered by the derive, whose kept items serialize with their
+    /// `Serialize` implementation where one is known, like `Serialized`, rather than as their
+    /// `Debug` output.
+    ///
+    /// The implementation is chosen by method resolution on `&&&SerializedTruncated(value)`,
+    /// trying `SerializedTruncatedValue`, `SerializedTruncatedDeref` and then
+    /// `SerializedTruncatedDebug`.
+    #[doc(hidden)]
+    pub struct SerializedTruncated<'a, T: ?Sized>(pub &'a T);
+
+    #[doc(hidden)]
+    pub trait SerializedTruncatedValue {
+        fn async_debug_truncated_serialized(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered;
+    }
+
+    impl<T: AsyncDebugTruncateSerialize + ?Sized> SerializedTruncatedValue
+        for &&SerializedTruncated<'_, T>
+    {
+        fn async_debug_truncated_serialized(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            self.0.async_debug_truncated_structure(rendered, ctx)
+        }
+    }
+
+    #[doc(hidden)]
+    pub trait SerializedTruncatedDeref {
+        fn async_debug_truncated_serialized(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered;
+    }
+
+    impl<T: Deref + ?Sized> SerializedTruncatedDeref for &SerializedTruncated<'_, T>
+    where
+        T::Target: AsyncDebugTruncateSerialize,
+    {
+        fn async_debug_truncated_serialized(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            (**self.0).async_debug_truncated_structure(rendered, ctx)
+        }
+    }
+
+    #[doc(hidden)]
+    pub trait SerializedTruncatedDebug {
+        fn async_debug_truncated_serialized(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered;
+    }
+
+    impl<T: ?Sized> SerializedTruncatedDebug for SerializedTruncated<'_, T> {
+        fn async_debug_truncated_serialized(
+            &self,
+            rendered: Rendered,
+            _ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            rendered
+        }
+    }
+
+    /// A value `AsyncDebugTruncate` renders the items of, which can be serialized
+    #[doc(hidden)]
+    pub trait AsyncDebugTruncateSerialize {
+        /// `rendered`, the rendering of `self` within the limits of `ctx`, serialized as the items
+        /// it kept
+        fn async_debug_truncated_structure(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered;
+    }
+
+    macro_rules! impl_deref {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                impl<T: AsyncDebugTruncateSerialize + ?Sized> AsyncDebugTruncateSerialize for $ty {
+                    fn async_debug_truncated_structure(
+                        &self,
+                        rendered: Rendered,
+                        ctx: &AsyncDebugContext,
+                    ) -> Rendered {
+                        (**self).async_debug_truncated_structure(rendered, ctx)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_deref!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
+
+    impl<T: AsyncDebugTruncateSerialize> AsyncDebugTruncateSerialize for Option<T> {
+        fn async_debug_truncated_structure(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            match self {
+                Some(value) => value.async_debug_truncated_structure(rendered, ctx),
+                None => rendered,
+            }
+        }
+    }
+
+    /// `rendered` serialized as the first `max_items` of `items`
+    fn serialized_items<'a, T: Debug + Serialize + 'a>(
+        rendered: Rendered,
+        items: impl IntoIterator<Item = &'a T>,
+        ctx: &AsyncDebugContext,
+    ) -> Rendered {
+        rendered.with_structure(|| {
+            Structure::Seq(
+                items
+                    .into_iter()
+                    .take(ctx.max_items().unwrap_or(usize::MAX))
+                    .map(Structure::serialized)
+                    .collect(),
+            )
+        })
+    }
+
+    macro_rules! impl_items {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                impl<T: Debug + Serialize> AsyncDebugTruncateSerialize for $ty {
+                    fn async_debug_truncated_structure(
+                        &self,
+                        rendered: Rendered,
+                        ctx: &AsyncDebugContext,
+                    ) -> Rendered {
+                        serialized_items(rendered, self, ctx)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_items!([T], Vec<T>, VecDeque<T>, BTreeSet<T>);
+
+    impl<T: Debug + Serialize, const N: usize> AsyncDebugTruncateSerialize for [T; N] {
+        fn async_debug_truncated_structure(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            serialized_items(rendered, self, ctx)
+        }
+    }
+
+    impl<T: Debug + Serialize, S> AsyncDebugTruncateSerialize for HashSet<T, S> {
+        fn async_debug_truncated_structure(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            serialized_items(rendered, self, ctx)
+        }
+    }
+
+    /// `rendered` serialized as the first `max_items` of `entries`
+    fn serialized_entries<'a, K: Debug + Serialize + 'a, V: Debug + Serialize + 'a>(
+        rendered: Rendered,
+        entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+        ctx: &AsyncDebugContext,
+    ) -> Rendered {
+        rendered.with_structure(|| {
+            Structure::Map(
+                entries
+                    .into_iter()
+                    .take(ctx.max_items().unwrap_or(usize::MAX))
+                    .map(|(key, value)| (Structure::serialized(key), Structure::serialized(value)))
+                    .collect(),
+            )
+        })
+    }
+
+    impl<K: Debug + Serialize, V: Debug + Serialize> AsyncDebugTruncateSerialize for BTreeMap<K, V> {
+        fn async_debug_truncated_structure(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            serialized_entries(rendered, self, ctx)
+        }
+    }
+
+    impl<K: Debug + Serialize, V: Debug + Serialize, S> AsyncDebugTruncateSerialize
+        for HashMap<K, V, S>
+    {
+        fn async_debug_truncated_structure(
+            &self,
+            rendered: Rendered,
+            ctx: &AsyncDebugContext,
+        ) -> Rendered {
+            serialized_entries(rendered, self, ctx)
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Condvar, Mutex, MutexGuard, OnceLock},
+    task::Waker,
+    thread,
+    time::Instant,
+};
+
+/// Wakes tasks at their deadlines from a single thread shared by every traversal, spawned on
+/// first use. Where threads are not supported, e.g. `wasm32-unknown-unknown`, there is no timer
+/// and deadlines are only checked when the task is polled for some other reason.
+pub(crate) struct Timer {
+    state: Mutex<TimerState>,
+    changed: Condvar,
+}
+
+#[derive(Default)]
+struct TimerState {
+    /// Waker of every pending deadline, the id keeps deadlines at the same instant apart
+    deadlines: BTreeMap<(Instant, u64), Waker>,
+    next_id: u64,
+}
+
+/// A deadline registered with the `Timer`, cancelled on drop
+pub(crate) struct TimerEntry {
+    key: (Instant, u64),
+}
+
+impl Timer {
+    /// The shared timer, `None` if its thread could not be spawned
+    fn get() -> Option<&'static Self> {
+        static TIMER: OnceLock<Option<&'static Timer>> = OnceLock::new();
+
+        *TIMER.get_or_init(|| {
+            let timer: &'static Timer = Box::leak(Box::new(Timer {
+                state: Mutex::default(),
+                changed: Condvar::new(),
+            }));
+
+            thread::Builder::new()
+                .name("async-debug-timer".into())
+                .spawn(move || timer.run())
+                .ok()
+                .map(|_| timer)
+        })
+    }
+
+    /// Wake `waker` at `deadline`, until the returned entry is dropped
+    pub(crate) fn register(deadline: Instant, waker: Waker) -> Option<TimerEntry> {
+        let timer = Self::get()?;
+        let mut state = timer.lock();
+
+        let key = (deadline, state.next_id);
+        state.next_id += 1;
+
+        state.deadlines.insert(key, waker);
+        let earliest = state.deadlines.keys().next() == Some(&key);
+        drop(state);
+
+        if earliest {
+            timer.changed.notify_one();
+        }
+
+        Some(TimerEntry { key })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TimerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn run(&self) {
+        let mut state = self.lock();
+
+        loop {
+            let now = Instant::now();
+            let mut expired = Vec::new();
+
+            while let Some(entry) = state.deadlines.first_entry() {
+                if entry.key().0 > now {
+                    break;
+                }
+
+                expired.push(entry.remove());
+            }
+
+            if !expired.is_empty() {
+                // Woken without the lock held, in case a waker polls the task in place
+                drop(state);
+                expired.into_iter().for_each(Waker::wake);
+                state = self.lock();
+                continue;
+            }
+
+            state = match state.deadlines.keys().next() {
+                Some(&(deadline, _)) => {
+                    let timeout = deadline.saturating_duration_since(now);
+                    self.changed
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .0
+                }
+                None => self
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()),
+            };
+        }
+    }
+}
+
+impl Drop for TimerEntry {
+    fn drop(&mut self) {
+        if let Some(timer) = Timer::get() {
+            timer.lock().deadlines.remove(&self.key);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{AsyncDebugContext, Rendered};
+
+/// Render a value as a `truncate`d field of a struct or enum deriving `AsyncDebug`, taking only
+/// the first `max_items` items or `max_len` characters, so the rest is never cloned or formatted.
+pub trait AsyncDebugTruncate {
+    /// Render `self` to an owned value within the limits of `ctx`
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered;
+}
+
+macro_rules! impl_deref {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: AsyncDebugTruncate + ?Sized> AsyncDebugTruncate for $ty {
+                fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+                    (**self).async_debug_truncated(ctx)
+                }
+            }
+        )*
+    };
+}
+
+impl_deref!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
+
+impl AsyncDebugTruncate for str {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        Rendered::str(self, ctx.max_len())
+    }
+}
+
+impl AsyncDebugTruncate for String {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        Rendered::str(self, ctx.max_len())
+    }
+}
+
+impl AsyncDebugTruncate for Cow<'_, str> {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        Rendered::str(self, ctx.max_len())
+    }
+}
+
+impl<T: AsyncDebugTruncate> AsyncDebugTruncate for Option<T> {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        match self {
+            Some(value) => Rendered::some(value.async_debug_truncated(ctx)),
+            None => Rendered::none(),
+        }
+    }
+}
+
+/// Render the first `max_items` items, as a list
+fn render_list<'a, T: Debug + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    ctx: &AsyncDebugContext,
+) -> Rendered {
+    let mut items = items.into_iter();
+    let rendered = items
+        .by_ref()
+        .take(ctx.max_items().unwrap_or(usize::MAX))
+        .map(Rendered::new)
+        .collect::<Vec<_>>();
+
+    Rendered::list(&rendered, items.count())
+}
+
+macro_rules! impl_list {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: Debug> AsyncDebugTruncate for $ty {
+                fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+                    render_list(self, ctx)
+                }
+            }
+        )*
+    };
+}
+
+impl_list!([T], Vec<T>, VecDeque<T>);
+
+impl<T: Debug, const N: usize> AsyncDebugTruncate for [T; N] {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        render_list(self, ctx)
+    }
+}
+
+/// Render the first `max_items` items, as a set
+fn render_set<'a, T: Debug + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    ctx: &AsyncDebugContext,
+) -> Rendered {
+    let mut items = items.into_iter();
+    let rendered = items
+        .by_ref()
+        .take(ctx.max_items().unwrap_or(usize::MAX))
+        .map(Rendered::new)
+        .collect::<Vec<_>>();
+
+    Rendered::set(&rendered, items.count())
+}
+
+impl<T: Debug> AsyncDebugTruncate for BTreeSet<T> {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        render_set(self, ctx)
+    }
+}
+
+impl<T: Debug, S> AsyncDebugTruncate for HashSet<T, S> {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        render_set(self, ctx)
+    }
+}
+
+/// Render the first `max_items` entries, as a map
+fn render_map<'a, K: Debug + 'a, V: Debug + 'a>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+    ctx: &AsyncDebugContext,
+) -> Rendered {
+    let mut entries = entries.into_iter();
+    let rendered = entries
+        .by_ref()
+        .take(ctx.max_items().unwrap_or(usize::MAX))
+        .map(|(key, value)| (Rendered::new(key), Rendered::new(value)))
+        .collect::<Vec<_>>();
+
+    Rendered::map(&rendered, entries.count())
+}
+
+impl<K: Debug, V: Debug> AsyncDebugTruncate for BTreeMap<K, V> {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        render_map(self, ctx)
+    }
+}
+
+impl<K: Debug, V: Debug, S> AsyncDebugTruncate for HashMap<K, V, S> {
+    fn async_debug_truncated(&self, ctx: &AsyncDebugContext) -> Rendered {
+        render_map(self, ctx)
+    }
+}
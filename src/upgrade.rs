@@ -0,0 +1,68 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    rc, sync,
+};
+
+/// A weak reference an `upgrade` field is upgraded through before its `async_call` is awaited,
+/// e.g. `#[async_debug(upgrade, async_call = RwLock::read, clone, ty = Parent)]`.
+pub trait AsyncDebugUpgrade {
+    /// Strong reference to the target
+    type Strong;
+
+    /// The strong reference, `None` once the target has been dropped
+    fn async_debug_upgrade(&self) -> Option<Self::Strong>;
+}
+
+impl<T: ?Sized> AsyncDebugUpgrade for sync::Weak<T> {
+    type Strong = sync::Arc<T>;
+
+    fn async_debug_upgrade(&self) -> Option<Self::Strong> {
+        self.upgrade()
+    }
+}
+
+impl<T: ?Sized> AsyncDebugUpgrade for rc::Weak<T> {
+    type Strong = rc::Rc<T>;
+
+    fn async_debug_upgrade(&self) -> Option<Self::Strong> {
+        self.upgrade()
+    }
+}
+
+/// Snapshot of the target of a weak reference, rendered as `<dropped>` once it is gone.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Upgraded<T> {
+    /// Snapshot of the target
+    Alive(T),
+    /// The target was dropped
+    Dropped,
+}
+
+impl<T> Upgraded<T> {
+    /// The snapshot of the target, `None` if it was dropped
+    pub fn alive(&self) -> Option<&T> {
+        match self {
+            Self::Alive(value) => Some(value),
+            Self::Dropped => None,
+        }
+    }
+}
+
+impl<T: Debug> Debug for Upgraded<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Alive(value) => value.fmt(f),
+            Self::Dropped => f.write_str("<dropped>"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: crate::serde::Serialize> crate::serde::Serialize for Upgraded<T> {
+    fn serialize<S: crate::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Alive(value) => value.serialize(serializer),
+            Self::Dropped => serializer.serialize_none(),
+        }
+    }
+}
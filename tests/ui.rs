@@ -5,4 +5,6 @@ fn ui() {
     let t = TestCases::new();
     t.pass("tests/ui/pass/*.rs");
     t.compile_fail("tests/ui/fail/*.rs");
+    #[cfg(feature = "serde")]
+    t.pass("tests/ui/serde/*.rs");
 }
@@ -0,0 +1,16 @@
+use async_debug::AsyncDebug;
+use tokio::sync::{Mutex, RwLock};
+
+#[derive(AsyncDebug)]
+struct Input {
+    #[async_debug(async_call = [each(RwLock::read), Mutex::lock], clone, ty = u64)]
+    each_not_last: Vec<RwLock<Mutex<u64>>>,
+    #[async_debug(async_call = [], clone, ty = u64)]
+    empty: u64,
+    #[async_debug(async_call = [Mutex::lock, each(RwLock::read)], clone)]
+    no_ty: Mutex<Vec<RwLock<u64>>>,
+    #[async_debug(async_call = [Mutex::lock, each(RwLock::read)], clone, ty = u64, skip_if_empty)]
+    skip_if_empty: Mutex<Vec<RwLock<u64>>>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug)]
+struct NotClone;
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(bound = "T: Clone")]
+struct Bounded<T: std::fmt::Debug>(#[async_debug(clone, ty = T)] T);
+
+#[tokio::main]
+async fn main() {
+    let bounded = Bounded(NotClone);
+
+    println!("{:?}", bounded.async_debug().await);
+}
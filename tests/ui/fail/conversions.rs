@@ -0,0 +1,16 @@
+use async_debug::AsyncDebug;
+use tokio::sync::RwLock;
+
+#[derive(AsyncDebug)]
+struct Input {
+    #[async_debug(to_owned, to_string)]
+    exclusive: String,
+    #[async_debug(into = String, ty = String)]
+    into_ty: String,
+    #[async_debug(async_call = RwLock::read, to_owned)]
+    to_owned_no_ty: RwLock<String>,
+    #[async_debug(to_string, nested)]
+    nested: String,
+}
+
+fn main() {}
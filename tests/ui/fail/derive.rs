@@ -0,0 +1,26 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(derive(Clone, Debug))]
+struct DeriveDebug {
+    name: String,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(derive(Clone, std::fmt::Debug))]
+struct DeriveDebugPath {
+    name: String,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(disable_derive_debug, derive(Debug))]
+struct DisableDeriveDebug {
+    name: String,
+}
+
+fn main() {
+    let _ = DisableDeriveDebug {
+        name: String::new(),
+    }
+    .async_debug();
+}
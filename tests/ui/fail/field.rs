@@ -0,0 +1,31 @@
+use async_debug::AsyncDebug;
+
+#[derive(AsyncDebug)]
+#[async_debug(field(name = "value", call = Borrowed::value))]
+struct Borrowed {
+    value_inner: u64,
+}
+
+#[derive(AsyncDebug)]
+#[async_debug(field(name = "value", ty = u64))]
+struct NoCall {
+    value_inner: u64,
+}
+
+#[derive(AsyncDebug)]
+#[async_debug(field(name = "value", call = Clash::value, ty = u64))]
+struct Clash {
+    value: u64,
+}
+
+#[derive(AsyncDebug)]
+#[async_debug(field(name = "total", call = After::total, ty = u64, after = "missing"))]
+struct After {
+    value: u64,
+}
+
+#[derive(AsyncDebug)]
+#[async_debug(field(name = "total", call = Tuple::total, ty = u64))]
+struct Tuple(u64);
+
+fn main() {}
@@ -0,0 +1,23 @@
+use async_debug::AsyncDebug;
+
+#[derive(AsyncDebug)]
+#[async_debug(field_vis = pub)]
+enum Enum {
+    Variant(u64),
+}
+
+mod private {
+    use async_debug::AsyncDebug;
+
+    #[derive(AsyncDebug)]
+    pub struct Private {
+        pub value: u64,
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let private = private::Private { value: 1 };
+
+    let _ = private.async_debug().await.value;
+}
@@ -0,0 +1,19 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+struct Core {
+    id: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Tuple(#[async_debug(flatten)] Core);
+
+#[derive(Debug, AsyncDebug)]
+struct Exclusive {
+    #[async_debug(flatten, nested)]
+    core: Core,
+    #[async_debug(profile(brief, flatten))]
+    other: Core,
+}
+
+fn main() {}
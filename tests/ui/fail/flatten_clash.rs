@@ -0,0 +1,29 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+struct Core {
+    id: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Inner {
+    #[async_debug(flatten)]
+    core: Core,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Clash {
+    id: u64,
+    #[async_debug(flatten)]
+    inner: Inner,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Pair {
+    #[async_debug(flatten)]
+    core: Core,
+    #[async_debug(flatten)]
+    inner: Inner,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use async_debug::AsyncDebug;
+
+#[derive(AsyncDebug)]
+#[async_debug(getters)]
+struct Tuple(u64, String);
+
+#[derive(AsyncDebug)]
+#[async_debug(field_vis = pub, getters)]
+struct TupleFieldVis(#[async_debug(skip)] u64, String);
+
+fn main() {}
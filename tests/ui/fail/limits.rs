@@ -0,0 +1,15 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+struct Limits {
+    #[async_debug(max_items = 2, clone, ty = Vec<u64>)]
+    cloned: Vec<u64>,
+    #[async_debug(max_depth = 2)]
+    depth: Vec<u64>,
+    #[async_debug(nested, truncate)]
+    nested: Vec<u64>,
+    #[async_debug(max_len = "3")]
+    len: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use async_debug::AsyncDebug;
+use tokio::sync::Mutex;
+
+#[derive(AsyncDebug)]
+struct Input {
+    #[async_debug(async_call = Mutex::lock, map = Vec::len)]
+    no_ty: Mutex<Vec<u64>>,
+    #[async_debug(map = |v: &Vec<u64>| v.len(), ty = usize)]
+    closure: Vec<u64>,
+    #[async_debug(map = Vec::len, ty = usize, skip)]
+    skip: Vec<u64>,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+struct Inner {
+    value: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Outer {
+    #[async_debug(nested, clone)]
+    cloned: Inner,
+    #[async_debug(nested, ty = Inner)]
+    typed: Inner,
+    #[async_debug(nested, skip)]
+    skipped: Inner,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use async_debug::AsyncDebug;
+use tokio::sync::Mutex;
+
+#[derive(AsyncDebug)]
+struct NotOption {
+    #[async_debug(optional, async_call = Mutex::lock, copy, ty = u64)]
+    value: Mutex<u64>,
+}
+
+#[derive(AsyncDebug)]
+struct SkipIf {
+    #[async_debug(optional, skip_if_empty)]
+    values: Option<Vec<u64>>,
+}
+
+#[derive(AsyncDebug)]
+struct Profile {
+    #[async_debug(profile(short, optional))]
+    value: Option<u64>,
+}
+
+fn main() {}
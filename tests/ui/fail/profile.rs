@@ -0,0 +1,19 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+struct Profiles {
+    #[async_debug(profile(with))]
+    with: u64,
+    #[async_debug(profile(brief, attr(allow(dead_code))))]
+    attr: u64,
+    #[async_debug(profile(brief, clone, copy))]
+    exclusive: u64,
+    #[async_debug(profile(brief, skip, clone))]
+    skip: u64,
+    #[async_debug(skip, profile(brief))]
+    skipped: u64,
+    #[async_debug(profile(brief, unknown))]
+    unknown: u64,
+}
+
+fn main() {}
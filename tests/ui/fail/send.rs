@@ -0,0 +1,20 @@
+use std::{cell::Cell, rc::Rc};
+
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(send)]
+struct NotSync {
+    name: String,
+    counter: Cell<u64>,
+    #[async_debug(skip)]
+    shared: Rc<String>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(send, local)]
+struct Exclusive {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use async_debug::AsyncDebug;
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(transparent)]
+struct Two {
+    a: u64,
+    b: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(transparent)]
+struct SkipIf {
+    #[async_debug(skip_if_empty)]
+    a: String,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(transparent)]
+enum Enum {
+    A(u64),
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(transparent, non_exhaustive)]
+struct NonExhaustive(u64);
+
+fn main() {}
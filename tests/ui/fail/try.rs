@@ -0,0 +1,29 @@
+use async_debug::AsyncDebug;
+
+struct Remote;
+
+impl Remote {
+    async fn status(&self) -> Result<u64, ()> {
+        Ok(1)
+    }
+}
+
+#[derive(AsyncDebug)]
+struct NoAsyncCall {
+    #[async_debug(try, clone)]
+    value: Result<u64, ()>,
+}
+
+#[derive(AsyncDebug)]
+struct Borrowed {
+    #[async_debug(async_call = Remote::status, try)]
+    remote: Remote,
+}
+
+#[derive(AsyncDebug)]
+struct SkipIf {
+    #[async_debug(async_call = Remote::status, try, ty = u64, skip_if = is_zero)]
+    remote: Remote,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use std::sync::{Arc, Weak};
+
+use async_debug::AsyncDebug;
+use tokio::sync::RwLock;
+
+#[derive(AsyncDebug)]
+struct NotWeak {
+    #[async_debug(upgrade, async_call = RwLock::read, clone, ty = u64)]
+    value: Arc<RwLock<u64>>,
+}
+
+#[derive(AsyncDebug)]
+struct Borrowed {
+    #[async_debug(upgrade)]
+    value: Weak<u64>,
+}
+
+#[derive(AsyncDebug)]
+struct SkipIf {
+    #[async_debug(upgrade, clone, skip_if_empty)]
+    values: Weak<Vec<u64>>,
+}
+
+fn main() {}
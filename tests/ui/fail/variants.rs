@@ -0,0 +1,21 @@
+use async_debug::AsyncDebug;
+
+#[derive(AsyncDebug)]
+enum Exclusive {
+    #[async_debug(skip, name_only)]
+    A(u64),
+}
+
+#[derive(AsyncDebug)]
+enum RenameTransparent {
+    #[async_debug(rename = "B", transparent)]
+    A(u64),
+}
+
+#[derive(AsyncDebug)]
+enum TransparentTwo {
+    #[async_debug(transparent)]
+    A(u64, u64),
+}
+
+fn main() {}
@@ -0,0 +1,73 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::{Mutex, RwLock};
+
+#[derive(Clone, Debug)]
+struct Entry {
+    count: u64,
+}
+
+#[derive(AsyncDebug)]
+struct Registry {
+    #[async_debug(async_call = [Mutex::lock, each(RwLock::read)], clone, ty = Entry)]
+    entries: Mutex<BTreeMap<String, Arc<RwLock<Entry>>>>,
+    #[async_debug(async_call = [Mutex::lock, RwLock::read], copy, ty = u64)]
+    version: Mutex<Arc<RwLock<u64>>>,
+    #[async_debug(async_call = each([Mutex::lock, each(RwLock::read)]), copy, ty = u8)]
+    shards: Vec<Mutex<Vec<RwLock<u8>>>>,
+    #[async_debug(async_call = [RwLock::read, each(Mutex::lock)], truncate, max_len = 2)]
+    names: RwLock<Vec<Mutex<String>>>,
+}
+
+#[tokio::main]
+async fn main() {
+    let a = Arc::new(RwLock::new(Entry { count: 1 }));
+    let registry = Registry {
+        entries: Mutex::new(BTreeMap::from([
+            ("a".into(), a.clone()),
+            ("b".into(), Arc::new(RwLock::new(Entry { count: 2 }))),
+        ])),
+        version: Mutex::new(Arc::new(RwLock::new(3))),
+        shards: vec![
+            Mutex::new(vec![RwLock::new(4), RwLock::new(5)]),
+            Mutex::new(vec![]),
+        ],
+        names: RwLock::new(vec![Mutex::new("first".into())]),
+    };
+
+    let expected = "Registry { \
+        entries: {\"a\": Entry { count: 1 }, \"b\": Entry { count: 2 }}, \
+        version: 3, \
+        shards: [[4, 5], []], \
+        names: [\"fi\" .. 3 more] \
+    }";
+
+    let snapshot = registry.async_debug().await;
+    assert_eq!(snapshot.entries.items()[1].count, 2);
+    assert_eq!(format!("{:?}", snapshot), expected);
+    assert_eq!(
+        format!(
+            "{:?}",
+            registry.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        expected
+    );
+
+    let _entry = a.write().await;
+    let _names = registry.names.write().await;
+    assert_eq!(
+        format!(
+            "{:?}",
+            registry
+                .async_debug_with(&AsyncDebugOptions::new().with_try_lock())
+                .await
+        ),
+        "Registry { \
+            entries: {\"a\": <locked>, \"b\": Entry { count: 2 }}, \
+            version: 3, \
+            shards: [[4, 5], []], \
+            names: <locked> \
+        }"
+    );
+}
@@ -0,0 +1,26 @@
+use async_debug::AsyncDebug;
+
+// The inferred `Vec<Tree<T>>: AsyncDebugNested` bound would require itself
+#[derive(Debug, AsyncDebug)]
+#[async_debug(recursive, bound = "T: std::fmt::Debug + Send + Sync")]
+struct Tree<T> {
+    value: T,
+    #[async_debug(nested)]
+    children: Vec<Tree<T>>,
+}
+
+#[tokio::main]
+async fn main() {
+    let tree = Tree {
+        value: 1,
+        children: vec![Tree {
+            value: 2,
+            children: Vec::new(),
+        }],
+    };
+
+    assert_eq!(
+        format!("{:?}", tree.async_debug().await),
+        "Tree { value: 1, children: [Tree { value: 2, children: [] }] }",
+    );
+}
@@ -0,0 +1,58 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_debug::AsyncDebug;
+use tokio::sync::{Mutex, RwLock};
+
+struct Url(String);
+
+impl Display for Url {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "https://{}/", self.0)
+    }
+}
+
+#[derive(AsyncDebug)]
+struct Resource {
+    #[async_debug(async_call = Mutex::lock, to_string)]
+    url: Mutex<Url>,
+    #[async_debug(async_call = RwLock::read, into = PathBuf)]
+    path: RwLock<Box<Path>>,
+    #[async_debug(async_call = RwLock::read, to_owned, ty = Vec<u16>)]
+    ports: Arc<RwLock<[u16]>>,
+    #[async_debug(to_owned)]
+    label: Box<str>,
+    #[async_debug(to_string)]
+    weight: u16,
+}
+
+#[tokio::main]
+async fn main() {
+    let resource = Resource {
+        url: Mutex::new(Url("example.com".into())),
+        path: RwLock::new(Path::new("/var/db").into()),
+        ports: Arc::new(RwLock::new([5432, 5433])),
+        label: "primary".into(),
+        weight: 3,
+    };
+
+    let snapshot = resource.async_debug().await;
+    assert_eq!(snapshot.url, "https://example.com/");
+    assert_eq!(snapshot.path, PathBuf::from("/var/db"));
+    assert_eq!(snapshot.ports, vec![5432, 5433]);
+    assert_eq!(&*snapshot.label, "primary");
+    assert_eq!(snapshot.weight, "3");
+    assert_eq!(
+        format!("{:?}", snapshot),
+        "Resource { \
+            url: \"https://example.com/\", \
+            path: \"/var/db\", \
+            ports: [5432, 5433], \
+            label: \"primary\", \
+            weight: \"3\" \
+        }"
+    );
+}
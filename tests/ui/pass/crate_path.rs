@@ -0,0 +1,39 @@
+use tokio::sync::RwLock;
+
+mod facade {
+    pub use async_debug;
+}
+
+#[derive(async_debug::AsyncDebug)]
+struct Absolute {
+    #[async_debug(async_call = RwLock::read, clone, ty = String)]
+    rw_lock: RwLock<String>,
+}
+
+#[derive(facade::async_debug::AsyncDebug)]
+#[async_debug(crate = "crate::facade::async_debug", snapshot = FacadeSnapshot)]
+struct Facade {
+    string: String,
+}
+
+fn render(snapshot: FacadeSnapshot<'_>) -> String {
+    format!("{:?}", snapshot)
+}
+
+#[tokio::main]
+async fn main() {
+    let absolute = Absolute {
+        rw_lock: RwLock::from("test".to_string()),
+    };
+
+    assert_eq!(
+        format!("{:?}", absolute.async_debug().await),
+        "Absolute { rw_lock: \"test\" }",
+    );
+
+    let facade = Facade {
+        string: "test".into(),
+    };
+
+    assert_eq!(render(facade.async_debug().await), "Facade { string: \"test\" }");
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use async_debug::AsyncDebug;
+use tokio::sync::Mutex;
+
+#[derive(Debug, AsyncDebug)]
+struct Connection {
+    id: u64,
+    #[async_debug(nested)]
+    pool: Arc<Pool>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(recursive)]
+struct Pool {
+    name: String,
+    #[async_debug(async_call = Mutex::lock, nested)]
+    connections: Mutex<Vec<Arc<Connection>>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Shared {
+    #[async_debug(nested)]
+    left: Arc<Pool>,
+    #[async_debug(nested)]
+    right: Arc<Pool>,
+}
+
+#[tokio::main]
+async fn main() {
+    let pool = Arc::new(Pool {
+        name: "pool".into(),
+        connections: Mutex::default(),
+    });
+    let connections = (1..=2)
+        .map(|id| {
+            Arc::new(Connection {
+                id,
+                pool: pool.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    *pool.connections.lock().await = connections.clone();
+
+    let rendered = format!("{:?}", connections[0].async_debug().await);
+    let expected = format!(
+        "Connection {{ id: 1, pool: Pool {{ name: \"pool\", connections: [<cycle: Connection@{:p}>, Connection {{ id: 2, pool: <cycle: Pool@{:p}> }}] }} }}",
+        Arc::as_ptr(&connections[0]),
+        Arc::as_ptr(&pool),
+    );
+    assert_eq!(rendered, expected);
+
+    let rendered = format!("{:?}", pool.async_debug().await);
+    let expected = format!(
+        "Pool {{ name: \"pool\", connections: [Connection {{ id: 1, pool: <cycle: Pool@{0:p}> }}, Connection {{ id: 2, pool: <cycle: Pool@{0:p}> }}] }}",
+        Arc::as_ptr(&pool),
+    );
+    assert_eq!(rendered, expected);
+
+    let empty = Arc::new(Pool {
+        name: "empty".into(),
+        connections: Mutex::default(),
+    });
+    let shared = Shared {
+        left: empty.clone(),
+        right: empty,
+    };
+    assert_eq!(
+        format!("{:?}", shared.async_debug().await),
+        "Shared { left: Pool { name: \"empty\", connections: [] }, right: Pool { name: \"empty\", connections: [] } }",
+    );
+}
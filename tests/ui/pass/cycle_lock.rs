@@ -0,0 +1,99 @@
+use std::{sync::Arc, time::Duration};
+
+use async_debug::AsyncDebug;
+use tokio::{sync::Mutex, time::timeout};
+
+#[derive(Debug, AsyncDebug)]
+struct Connection {
+    id: u64,
+    #[async_debug(async_call = Mutex::lock, nested)]
+    pool: Arc<Mutex<Pool>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(recursive)]
+struct Pool {
+    #[async_debug(nested)]
+    connections: Vec<Arc<Connection>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Session {
+    id: u64,
+    #[async_debug(async_call = Mutex::lock, nested)]
+    registry: Arc<Mutex<Registry>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(recursive)]
+struct Registry {
+    #[async_debug(async_call = each(Mutex::lock), nested)]
+    sessions: Vec<Arc<Mutex<Session>>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Client {
+    #[async_debug(async_call = Mutex::lock, nested)]
+    registry: Arc<Mutex<Registry>>,
+}
+
+#[tokio::main]
+async fn main() {
+    let pool = Arc::new(Mutex::new(Pool {
+        connections: Vec::new(),
+    }));
+    let connections = (1..=2)
+        .map(|id| {
+            Arc::new(Connection {
+                id,
+                pool: pool.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    pool.lock().await.connections = connections.clone();
+
+    let rendered = timeout(Duration::from_secs(5), connections[0].async_debug())
+        .await
+        .expect("locking the pool again deadlocked");
+    let expected = format!(
+        "Connection {{ id: 1, pool: Pool {{ connections: [<cycle: Connection@{:p}>, Connection {{ id: 2, pool: <cycle: Mutex@{:p}> }}] }} }}",
+        Arc::as_ptr(&connections[0]),
+        Arc::as_ptr(&pool),
+    );
+    assert_eq!(format!("{:?}", rendered), expected);
+
+    let rendered = timeout(
+        Duration::from_secs(5),
+        connections[0].async_debug_with(&Default::default()),
+    )
+    .await
+    .expect("locking the pool again deadlocked");
+    assert_eq!(format!("{:?}", rendered), expected);
+
+    let registry = Arc::new(Mutex::new(Registry {
+        sessions: Vec::new(),
+    }));
+    let sessions = (1..=2)
+        .map(|id| {
+            Arc::new(Mutex::new(Session {
+                id,
+                registry: registry.clone(),
+            }))
+        })
+        .collect::<Vec<_>>();
+    registry.lock().await.sessions = sessions;
+    let client = Client {
+        registry: registry.clone(),
+    };
+
+    let rendered = timeout(Duration::from_secs(5), client.async_debug())
+        .await
+        .expect("locking the registry again deadlocked");
+    assert_eq!(
+        format!("{:?}", rendered),
+        format!(
+            "Client {{ registry: Registry {{ sessions: [Session {{ id: 1, registry: <cycle: Mutex@{0:p}> }}, Session {{ id: 2, registry: <cycle: Mutex@{0:p}> }}] }} }}",
+            Arc::as_ptr(&registry),
+        ),
+    );
+}
@@ -0,0 +1,48 @@
+use async_debug::AsyncDebug;
+use tokio::sync::{Mutex, RwLock};
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(derive(Clone, PartialEq))]
+struct StructNamed {
+    string: String,
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<String>)]
+    rw_lock: RwLock<Vec<String>>,
+    #[async_debug(async_call = Mutex::lock, copy, ty = u128, attr(doc = "Copied"))]
+    mutex_u128: Mutex<u128>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(derive(Clone, PartialEq, Eq))]
+enum EnumUnnamed {
+    Variant1(
+        String,
+        #[async_debug(async_call = Mutex::lock, clone, ty = Vec<u64>)] Mutex<Vec<u64>>,
+    ),
+}
+
+#[tokio::main]
+async fn main() {
+    let struct_named = StructNamed {
+        string: "test".into(),
+        rw_lock: RwLock::from(vec!["string0".into(), "string1".into()]),
+        mutex_u128: Mutex::from(999),
+    };
+
+    let snapshot = struct_named.async_debug().await;
+
+    assert_eq!(snapshot.clone(), struct_named.async_debug().await);
+    assert_eq!(
+        format!("{:?}", snapshot),
+        "StructNamed { string: \"test\", rw_lock: [\"string0\", \"string1\"], mutex_u128: 999 }",
+    );
+
+    let enum_unnamed = EnumUnnamed::Variant1("test".into(), Mutex::from(vec![0, 1]));
+
+    let snapshot = enum_unnamed.async_debug().await;
+
+    assert_eq!(snapshot.clone(), enum_unnamed.async_debug().await);
+    assert_eq!(
+        format!("{:?}", snapshot),
+        "Variant1(\"test\", [0, 1])",
+    );
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::Mutex;
+
+#[derive(Debug, PartialEq)]
+enum Health {
+    Ok,
+}
+
+#[derive(AsyncDebug)]
+#[async_debug(field(name = "uptime", call = Service::uptime, ty = Duration))]
+#[async_debug(field(name = "health", async_call = Service::health, ty = Health, after = "name"))]
+#[async_debug(field(name = "queue_depth", async_call = Service::queue_depth, ty = usize, after = "name"))]
+#[async_debug(field(name = "jobs", async_call = Service::jobs, truncate, max_items = 1, profile(brief, skip)))]
+struct Service {
+    name: String,
+    #[async_debug(skip)]
+    started: u64,
+    #[async_debug(skip)]
+    queue: Mutex<Vec<String>>,
+}
+
+impl Service {
+    fn uptime(&self) -> Duration {
+        Duration::from_secs(self.started)
+    }
+
+    async fn health(&self) -> Health {
+        Health::Ok
+    }
+
+    async fn queue_depth(&self) -> usize {
+        self.queue.lock().await.len()
+    }
+
+    async fn jobs(&self) -> Vec<String> {
+        self.queue.lock().await.clone()
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let service = Service {
+        name: "service".into(),
+        started: 3,
+        queue: Mutex::new(vec!["a".into(), "b".into()]),
+    };
+
+    let snapshot = service.async_debug().await;
+    assert_eq!(snapshot.uptime, Duration::from_secs(3));
+    assert_eq!(snapshot.health, Health::Ok);
+    assert_eq!(snapshot.queue_depth, 2);
+    assert_eq!(
+        format!("{:?}", snapshot),
+        "Service { name: \"service\", health: Ok, queue_depth: 2, uptime: 3s, jobs: [\"a\", .. 1 more] }"
+    );
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            service
+                .async_debug_with(&AsyncDebugOptions::new().with_redacted("health"))
+                .await
+        ),
+        "Service { name: \"service\", health: <redacted>, queue_depth: 2, uptime: 3s, jobs: [\"a\", .. 1 more] }"
+    );
+    assert_eq!(
+        format!("{:?}", service.async_debug_brief().await),
+        "Service { name: \"service\", health: Ok, queue_depth: 2, uptime: 3s }"
+    );
+}
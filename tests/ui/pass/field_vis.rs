@@ -0,0 +1,64 @@
+mod service {
+    use async_debug::AsyncDebug;
+    use tokio::sync::Mutex;
+
+    #[derive(AsyncDebug)]
+    #[async_debug(field_vis = pub)]
+    pub struct Service {
+        pub name: String,
+        #[async_debug(async_call = Mutex::lock, clone, ty = Vec<u64>)]
+        pub queue: Mutex<Vec<u64>>,
+    }
+
+    #[derive(AsyncDebug)]
+    #[async_debug(getters)]
+    #[async_debug(field(name = "depth", async_call = Worker::depth, ty = usize))]
+    pub(crate) struct Worker {
+        pub(crate) id: u64,
+        #[async_debug(skip)]
+        pub(crate) queue: Mutex<Vec<u64>>,
+        #[async_debug(async_call = Mutex::lock, clone, ty = String, skip_if = String::is_empty)]
+        pub(crate) status: Mutex<String>,
+    }
+
+    impl Worker {
+        async fn depth(&self) -> usize {
+            self.queue.lock().await.len()
+        }
+    }
+
+    #[derive(AsyncDebug)]
+    #[async_debug(field_vis = pub(super))]
+    pub struct Pair(pub u64, #[async_debug(async_call = Mutex::lock, copy, ty = u64)] pub Mutex<u64>);
+}
+
+use service::{Pair, Service, Worker};
+use tokio::sync::Mutex;
+
+#[tokio::main]
+async fn main() {
+    let service = Service {
+        name: "service".into(),
+        queue: Mutex::new(vec![1, 2, 3]),
+    };
+
+    let snapshot = service.async_debug().await;
+    assert_eq!(snapshot.name, "service");
+    assert_eq!(snapshot.queue.len(), 3);
+
+    let worker = Worker {
+        id: 7,
+        queue: Mutex::new(vec![1]),
+        status: Mutex::new(String::new()),
+    };
+
+    let snapshot = worker.async_debug().await;
+    assert_eq!(*snapshot.id(), &7);
+    assert_eq!(*snapshot.status(), None);
+    assert_eq!(*snapshot.depth(), 1);
+
+    let pair = Pair(1, Mutex::new(2));
+
+    let snapshot = pair.async_debug().await;
+    assert_eq!((snapshot.0, snapshot.1), (&1, 2));
+}
@@ -0,0 +1,83 @@
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::RwLock;
+
+#[derive(Debug, AsyncDebug)]
+struct Core {
+    id: u64,
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>)]
+    pending: RwLock<Vec<u64>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Stats<T> {
+    #[async_debug(profile(brief, skip))]
+    count: T,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Service<T> {
+    name: String,
+    #[async_debug(flatten)]
+    core: Core,
+    #[async_debug(flatten, profile(brief, skip))]
+    stats: Box<Stats<T>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Outer {
+    #[async_debug(flatten)]
+    service: Service<u8>,
+    #[async_debug(nested)]
+    services: Vec<Service<u8>>,
+}
+
+#[tokio::main]
+async fn main() {
+    let service = Service {
+        name: "service".into(),
+        core: Core {
+            id: 1,
+            pending: RwLock::new(vec![2]),
+        },
+        stats: Box::new(Stats { count: 3 }),
+    };
+    let expected = "Service { name: \"service\", id: 1, pending: [2], count: 3 }";
+
+    assert_eq!(format!("{:?}", service.async_debug().await), expected);
+    assert_eq!(
+        format!(
+            "{:?}",
+            service.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        expected
+    );
+    assert_eq!(
+        format!("{:#?}", service.async_debug().await),
+        format!(
+            "{:#?}",
+            service.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+    );
+    assert_eq!(
+        format!("{:?}", service.async_debug_brief().await),
+        "Service { name: \"service\", id: 1, pending: [2] }"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            service
+                .async_debug_with(&AsyncDebugOptions::new().with_redacted("core"))
+                .await
+        ),
+        "Service { name: \"service\", core: <redacted>, count: 3 }"
+    );
+
+    let outer = Outer {
+        service,
+        services: Vec::new(),
+    };
+    assert_eq!(
+        format!("{:?}", outer.async_debug().await),
+        "Outer { name: \"service\", id: 1, pending: [2], count: 3, services: [] }"
+    );
+}
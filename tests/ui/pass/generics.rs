@@ -0,0 +1,66 @@
+use std::fmt::Debug;
+
+use async_debug::AsyncDebug;
+use tokio::sync::Mutex;
+
+#[derive(Debug, AsyncDebug)]
+struct Borrowed<'a, 'b: 'a, T: ?Sized + Debug> {
+    name: &'a str,
+    value: &'b T,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Array<T: Debug + Copy, const N: usize = 2> {
+    #[async_debug(async_call = Mutex::lock, copy, ty = [T; N])]
+    values: Mutex<[T; N]>,
+}
+
+#[derive(Debug, AsyncDebug)]
+enum Either<'a, L = u8, R = &'static str>
+where
+    for<'x> &'x L: Debug,
+    R: Debug,
+{
+    Left(&'a L),
+    Right { value: R },
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(bound = "T: Clone")]
+struct Bounded<T: Debug>(#[async_debug(clone, ty = T)] T);
+
+#[tokio::main]
+async fn main() {
+    let value = 42u64;
+    let borrowed = Borrowed {
+        name: "test",
+        value: &value,
+    };
+    assert_eq!(
+        format!("{:?}", borrowed.async_debug().await),
+        "Borrowed { name: \"test\", value: 42 }",
+    );
+
+    let array = Array {
+        values: Mutex::new([1u8, 2]),
+    };
+    assert_eq!(
+        format!("{:?}", array.async_debug().await),
+        "Array { values: [1, 2] }",
+    );
+
+    let left: Either = Either::Left(&7);
+    assert_eq!(format!("{:?}", left.async_debug().await), "Left(7)");
+
+    let right: Either = Either::Right { value: "right" };
+    assert_eq!(
+        format!("{:?}", right.async_debug().await),
+        "Right { value: \"right\" }",
+    );
+
+    let bounded = Bounded(vec![1, 2, 3]);
+    assert_eq!(
+        format!("{:?}", bounded.async_debug().await),
+        "Bounded([1, 2, 3])",
+    );
+}
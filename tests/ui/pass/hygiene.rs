@@ -0,0 +1,76 @@
+#![allow(non_camel_case_types)]
+
+use async_debug::AsyncDebug;
+use tokio::sync::Mutex;
+
+async fn lock(mutex: &Mutex<u64>) -> u64 {
+    *mutex.lock().await
+}
+
+#[derive(AsyncDebug)]
+struct FooBar {
+    value: u64,
+}
+
+#[derive(AsyncDebug)]
+struct Foo_Bar<'async_debug> {
+    value: &'async_debug str,
+}
+
+#[derive(AsyncDebug)]
+enum Collide {
+    A_b {
+        c: u64,
+    },
+    A {
+        #[async_debug(async_call = lock, ty = u64)]
+        b_c: Mutex<u64>,
+        lock: u64,
+    },
+}
+
+mod shadowed {
+    // `Debug` names a different derive macro in this module, the generated struct must still
+    // derive `core::fmt::Debug`
+    #[allow(unused_imports)]
+    use async_debug::AsyncDebug as Debug;
+    use async_debug::AsyncDebug;
+
+    #[derive(AsyncDebug)]
+    pub struct Shadowed {
+        pub value: u64,
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    assert_eq!(
+        format!("{:?}", FooBar { value: 1 }.async_debug().await),
+        "FooBar { value: 1 }",
+    );
+
+    assert_eq!(
+        format!("{:?}", Foo_Bar { value: "test" }.async_debug().await),
+        "Foo_Bar { value: \"test\" }",
+    );
+
+    assert_eq!(
+        format!("{:?}", Collide::A_b { c: 1 }.async_debug().await),
+        "A_b { c: 1 }",
+    );
+
+    let collide = Collide::A {
+        b_c: Mutex::from(2),
+        lock: 3,
+    };
+
+    assert_eq!(
+        format!("{:?}", collide.async_debug().await),
+        "A { b_c: 2, lock: 3 }",
+    );
+
+    assert_eq!(
+        format!("{:?}", shadowed::Shadowed { value: 4 }.async_debug().await),
+        "Shadowed { value: 4 }",
+    );
+}
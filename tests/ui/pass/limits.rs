@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use async_debug::AsyncDebug;
+use tokio::sync::RwLock;
+
+#[derive(Debug, AsyncDebug)]
+struct Leaf {
+    #[async_debug(truncate)]
+    name: String,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(max_items = 3, max_len = 5)]
+struct Limited {
+    #[async_debug(max_items = 2)]
+    values: Vec<u64>,
+    #[async_debug(async_call = RwLock::read, truncate)]
+    locked: RwLock<Vec<u64>>,
+    #[async_debug(max_len = 3)]
+    text: String,
+    #[async_debug(truncate)]
+    map: BTreeMap<u8, &'static str>,
+    #[async_debug(nested)]
+    leaves: Vec<Leaf>,
+    #[async_debug(nested, max_depth = 0)]
+    shallow: Option<Box<Limited>>,
+    untouched: Vec<u64>,
+}
+
+#[tokio::main]
+async fn main() {
+    let limited = Limited {
+        values: (0..10_000).collect(),
+        locked: RwLock::new(vec![1, 2, 3]),
+        text: "truncated".into(),
+        map: (0..5).map(|key| (key, "value")).collect(),
+        leaves: vec![
+            Leaf {
+                name: "short".into(),
+            },
+            Leaf {
+                name: "longer".into(),
+            },
+        ],
+        shallow: None,
+        untouched: vec![1, 2, 3, 4],
+    };
+
+    assert_eq!(
+        format!("{:?}", limited.async_debug().await),
+        "Limited { values: [0, 1, .. 9998 more], locked: [1, 2, 3], text: \"tru\" .. 6 more, \
+         map: {0: \"value\", 1: \"value\", 2: \"value\", .. 2 more}, \
+         leaves: [Leaf { name: \"short\" }, Leaf { name: \"longe\" .. 1 more }], \
+         shallow: None, untouched: [1, 2, 3, 4] }",
+    );
+
+    assert_eq!(
+        format!("{:#?}", limited.async_debug().await.values),
+        "[\n    0,\n    1,\n    .. 9998 more,\n]",
+    );
+
+    let leaf = Leaf {
+        name: "not limited at the root".into(),
+    };
+    assert_eq!(
+        format!("{:?}", leaf.async_debug().await),
+        "Leaf { name: \"not limited at the root\" }",
+    );
+}
@@ -0,0 +1,49 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::{Mutex, RwLock};
+
+fn key_set(map: &BTreeMap<String, u64>) -> BTreeSet<String> {
+    map.keys().cloned().collect()
+}
+
+fn latest(timestamps: &[u64]) -> Option<u64> {
+    timestamps.iter().copied().max()
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Queue {
+    #[async_debug(async_call = Mutex::lock, map = VecDeque::len, ty = usize)]
+    pending: Mutex<VecDeque<String>>,
+    #[async_debug(async_call = RwLock::read, map = key_set, ty = BTreeSet<String>)]
+    workers: RwLock<BTreeMap<String, u64>>,
+    #[async_debug(map = latest, ty = Option<u64>)]
+    timestamps: Vec<u64>,
+    #[async_debug(async_call = RwLock::read, map = key_set, truncate, max_items = 1)]
+    clients: RwLock<BTreeMap<String, u64>>,
+}
+
+#[tokio::main]
+async fn main() {
+    let queue = Queue {
+        pending: Mutex::new(VecDeque::from(vec!["a".into(), "b".into()])),
+        workers: RwLock::new(BTreeMap::from([("w1".into(), 1), ("w2".into(), 2)])),
+        timestamps: vec![3, 9, 4],
+        clients: RwLock::new(BTreeMap::from([("c1".into(), 1), ("c2".into(), 2)])),
+    };
+
+    let expected = "Queue { \
+        pending: 2, \
+        workers: {\"w1\", \"w2\"}, \
+        timestamps: Some(9), \
+        clients: {\"c1\", .. 1 more} \
+    }";
+
+    let snapshot = queue.async_debug().await;
+    assert_eq!(snapshot.pending, 2);
+    assert_eq!(format!("{:?}", snapshot), expected);
+    assert_eq!(
+        format!("{:?}", queue.async_debug_with(&AsyncDebugOptions::new()).await),
+        expected
+    );
+}
@@ -0,0 +1,97 @@
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(non_exhaustive)]
+struct Service {
+    a: u64,
+    #[async_debug(skip)]
+    b: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(non_exhaustive)]
+#[non_exhaustive]
+struct Declared(u64);
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(non_exhaustive)]
+struct Exhaustive {
+    a: u64,
+    #[async_debug(profile(brief, skip))]
+    b: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(non_exhaustive)]
+enum Task {
+    Waiting(u64, #[async_debug(skip)] u64),
+    #[non_exhaustive]
+    Done { a: u64 },
+    Other { a: u64 },
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Plain {
+    a: u64,
+    #[async_debug(skip)]
+    b: u64,
+}
+
+#[tokio::main]
+async fn main() {
+    let service = Service { a: 1, b: 2 };
+    assert_eq!(
+        format!("{:?}", service.async_debug().await),
+        "Service { a: 1, .. }"
+    );
+    assert_eq!(
+        format!("{:#?}", service.async_debug().await),
+        "Service {\n    a: 1,\n    ..\n}"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            service.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        "Service { a: 1, .. }"
+    );
+
+    let declared = Declared(1);
+    assert_eq!(
+        format!("{:?}", declared.async_debug().await),
+        "Declared(1, ..)"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            declared.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        "Declared(1, ..)"
+    );
+
+    let exhaustive = Exhaustive { a: 1, b: 2 };
+    assert_eq!(
+        format!("{:?}", exhaustive.async_debug().await),
+        "Exhaustive { a: 1, b: 2 }"
+    );
+    assert_eq!(
+        format!("{:?}", exhaustive.async_debug_brief().await),
+        "Exhaustive { a: 1, .. }"
+    );
+
+    assert_eq!(
+        format!("{:?}", Task::Waiting(1, 2).async_debug().await),
+        "Waiting(1, ..)"
+    );
+    assert_eq!(
+        format!("{:?}", Task::Done { a: 1 }.async_debug().await),
+        "Done { a: 1, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", Task::Other { a: 1 }.async_debug().await),
+        "Other { a: 1 }"
+    );
+
+    let plain = Plain { a: 1, b: 2 };
+    assert_eq!(format!("{:?}", plain.async_debug().await), "Plain { a: 1 }");
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::{Mutex, RwLock};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Conn {
+    port: u16,
+}
+
+#[derive(AsyncDebug)]
+struct Child {
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>)]
+    values: RwLock<Vec<u64>>,
+}
+
+#[derive(AsyncDebug)]
+struct Pool {
+    #[async_debug(optional, async_call = Mutex::lock, clone, ty = Conn)]
+    primary: Option<Arc<Mutex<Conn>>>,
+    #[async_debug(optional, async_call = Mutex::lock, clone, ty = Conn)]
+    replica: Option<Arc<Mutex<Conn>>>,
+    #[async_debug(optional, async_call = RwLock::read, to_string)]
+    name: std::option::Option<RwLock<String>>,
+    #[async_debug(optional)]
+    label: Option<String>,
+    #[async_debug(optional, async_call = Mutex::lock, nested)]
+    child: Option<Mutex<Child>>,
+}
+
+enum Slot {
+    Empty,
+    Full(Option<Mutex<u64>>),
+}
+
+#[derive(AsyncDebug)]
+enum Holder {
+    Slot(#[async_debug(optional, async_call = Mutex::lock, copy, ty = u64)] Option<Mutex<u64>>),
+}
+
+#[tokio::main]
+async fn main() {
+    let pool = Pool {
+        primary: Some(Arc::new(Mutex::new(Conn { port: 5432 }))),
+        replica: None,
+        name: Some(RwLock::new("main".into())),
+        label: None,
+        child: Some(Mutex::new(Child {
+            values: RwLock::new(vec![1, 2]),
+        })),
+    };
+
+    let snapshot = pool.async_debug().await;
+    assert_eq!(snapshot.primary, Some(Conn { port: 5432 }));
+    assert_eq!(snapshot.replica, None);
+    assert_eq!(snapshot.name.as_deref(), Some("main"));
+    assert_eq!(snapshot.label, None);
+
+    let expected = "Pool { \
+        primary: Some(Conn { port: 5432 }), \
+        replica: None, \
+        name: Some(\"main\"), \
+        label: None, \
+        child: Some(Child { values: [1, 2] }) \
+    }";
+
+    assert_eq!(format!("{:?}", snapshot), expected);
+    assert_eq!(
+        format!("{:?}", pool.async_debug_with(&AsyncDebugOptions::new()).await),
+        expected
+    );
+
+    let holder = Holder::Slot(Some(Mutex::new(7)));
+    assert_eq!(format!("{:?}", holder.async_debug().await), "Slot(Some(7))");
+
+    let _ = [Slot::Empty, Slot::Full(None)];
+}
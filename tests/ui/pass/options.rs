@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::{Mutex, RwLock};
+
+#[derive(Debug, AsyncDebug)]
+struct Credentials {
+    user: String,
+    password: String,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(max_items = 2)]
+struct Service {
+    name: String,
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>)]
+    pending: RwLock<Vec<u64>>,
+    #[async_debug(async_call = Mutex::lock, nested)]
+    credentials: Mutex<Credentials>,
+    #[async_debug(truncate)]
+    history: Vec<u64>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(send)]
+enum State {
+    Running(#[async_debug(async_call = RwLock::read, clone, ty = String)] RwLock<String>),
+}
+
+#[tokio::main]
+async fn main() {
+    let service = Service {
+        name: "service".into(),
+        pending: RwLock::new(vec![1, 2, 3]),
+        credentials: Mutex::new(Credentials {
+            user: "admin".into(),
+            password: "hunter2".into(),
+        }),
+        history: (0..5).collect(),
+    };
+
+    assert_eq!(
+        format!("{:?}", service.async_debug_with(&AsyncDebugOptions::new()).await),
+        format!("{:?}", service.async_debug().await),
+    );
+
+    let pending = service.pending.write().await;
+    let credentials = service.credentials.lock().await;
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            service
+                .async_debug_with(&AsyncDebugOptions::new().with_try_lock())
+                .await
+        ),
+        "Service { name: \"service\", pending: <locked>, credentials: <locked>, \
+         history: [0, 1, .. 3 more] }",
+    );
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            service
+                .async_debug_with(
+                    &AsyncDebugOptions::new().with_timeout(Duration::from_millis(10))
+                )
+                .await
+        ),
+        "Service { name: \"service\", pending: <timed out>, credentials: <timed out>, \
+         history: [0, 1, .. 3 more] }",
+    );
+
+    let slow_options = AsyncDebugOptions::new().with_timeout(Duration::from_secs(60));
+    let slow = service.async_debug_with(&slow_options);
+    let fast = async {
+        tokio::task::yield_now().await;
+        service
+            .async_debug_with(&AsyncDebugOptions::new().with_timeout(Duration::from_millis(10)))
+            .await
+    };
+
+    tokio::select! {
+        _ = slow => panic!("the pending field was not timed out"),
+        rendered = fast => assert_eq!(
+            format!("{:?}", rendered),
+            "Service { name: \"service\", pending: <timed out>, credentials: <timed out>, \
+             history: [0, 1, .. 3 more] }",
+        ),
+    }
+
+    drop(pending);
+    drop(credentials);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            service
+                .async_debug_with(
+                    &AsyncDebugOptions::new()
+                        .with_max_items(4)
+                        .with_redacted("password")
+                        .with_redacted("pending")
+                )
+                .await
+        ),
+        "Service { name: \"service\", pending: <redacted>, \
+         credentials: Credentials { user: \"admin\", password: <redacted> }, \
+         history: [0, 1, 2, 3, .. 1 more] }",
+    );
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            service
+                .async_debug_with(&AsyncDebugOptions::new().with_max_depth(0))
+                .await
+        ),
+        "Service { name: \"service\", pending: [1, 2, 3], credentials: .., \
+         history: [0, 1, .. 3 more] }",
+    );
+
+    let state = State::Running(RwLock::new("ok".into()));
+    let rendered = tokio::spawn(async move {
+        state
+            .async_debug_with(&AsyncDebugOptions::new().with_redacted("0"))
+            .await
+    })
+    .await
+    .unwrap();
+    assert_eq!(format!("{:?}", rendered), "Running(<redacted>)");
+}
@@ -0,0 +1,94 @@
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::RwLock;
+
+#[derive(Debug, AsyncDebug)]
+struct Request {
+    id: u64,
+    #[async_debug(profile(brief, skip))]
+    headers: Vec<(String, String)>,
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>)]
+    #[async_debug(profile(brief, max_items = 2))]
+    chunks: RwLock<Vec<u64>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(send)]
+enum Handler {
+    Busy(
+        #[async_debug(nested, profile(full))] Request,
+        #[async_debug(profile(brief, skip))] u8,
+    ),
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Generic<T> {
+    #[async_debug(profile(brief, truncate))]
+    values: T,
+}
+
+#[tokio::main]
+async fn main() {
+    let request = Request {
+        id: 1,
+        headers: vec![("host".into(), "localhost".into())],
+        chunks: RwLock::new(vec![1, 2, 3]),
+    };
+
+    assert_eq!(
+        format!("{:?}", request.async_debug_brief().await),
+        "Request { id: 1, chunks: [1, 2, .. 1 more] }",
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            request.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        format!("{:?}", request.async_debug().await),
+    );
+
+    let handler = Handler::Busy(request, 2);
+    assert_eq!(
+        format!(
+            "{:?}",
+            handler
+                .async_debug_with(&AsyncDebugOptions::new().with_profile("brief"))
+                .await
+        ),
+        "Busy(Request { id: 1, chunks: [1, 2, .. 1 more] })",
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            tokio::spawn(async move { handler.async_debug_full().await })
+                .await
+                .unwrap()
+        ),
+        "Busy(Request { id: 1, headers: [(\"host\", \"localhost\")], chunks: [1, 2, 3] }, 2)",
+    );
+
+    let generic = Generic {
+        values: vec![1; 200],
+    };
+    assert_eq!(
+        format!(
+            "{:?}",
+            generic
+                .async_debug_with(&AsyncDebugOptions::new().with_max_items(1))
+                .await
+        ),
+        format!("Generic {{ values: {:?} }}", vec![1; 200]),
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            generic
+                .async_debug_with(
+                    &AsyncDebugOptions::new()
+                        .with_profile("brief")
+                        .with_max_items(1)
+                )
+                .await
+        ),
+        "Generic { values: [1, .. 199 more] }",
+    );
+}
@@ -0,0 +1,114 @@
+use std::{rc::Rc, sync::Arc};
+
+use async_debug::AsyncDebug;
+use tokio::sync::RwLock;
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(send)]
+struct Node {
+    name: String,
+    #[async_debug(async_call = RwLock::read, nested)]
+    children: RwLock<Vec<Node>>,
+}
+
+impl Node {
+    fn new(name: &str, children: Vec<Node>) -> Self {
+        Self {
+            name: name.into(),
+            children: RwLock::new(children),
+        }
+    }
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(max_depth = 2)]
+struct Shallow {
+    #[async_debug(nested)]
+    root: Arc<Node>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(recursive)]
+enum Expr {
+    Value(i64),
+    Add(#[async_debug(nested)] Box<Expr>, #[async_debug(nested)] Box<Expr>),
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(local)]
+struct LocalNode {
+    value: Rc<u8>,
+    #[async_debug(nested)]
+    children: Vec<LocalNode>,
+}
+
+#[tokio::main]
+async fn main() {
+    let tree = Arc::new(Node::new(
+        "root",
+        vec![
+            Node::new("a", vec![Node::new("a.a", vec![])]),
+            Node::new("b", vec![]),
+        ],
+    ));
+
+    let rendered = {
+        let tree = tree.clone();
+        tokio::spawn(async move { format!("{:?}", tree.async_debug().await) })
+            .await
+            .unwrap()
+    };
+    assert_eq!(
+        rendered,
+        "Node { name: \"root\", children: [Node { name: \"a\", children: [Node { name: \"a.a\", children: [] }] }, Node { name: \"b\", children: [] }] }",
+    );
+
+    assert_eq!(
+        format!("{:#?}", tree.async_debug().await),
+        r#"Node {
+    name: "root",
+    children: [
+        Node {
+            name: "a",
+            children: [
+                Node {
+                    name: "a.a",
+                    children: [],
+                },
+            ],
+        },
+        Node {
+            name: "b",
+            children: [],
+        },
+    ],
+}"#,
+    );
+
+    let shallow = Shallow { root: tree };
+    assert_eq!(
+        format!("{:?}", shallow.async_debug().await),
+        "Shallow { root: Node { name: \"root\", children: [Node { name: \"a\", children: .. }, Node { name: \"b\", children: .. }] } }",
+    );
+
+    let expr = Expr::Add(
+        Box::new(Expr::Value(1)),
+        Box::new(Expr::Add(Box::new(Expr::Value(2)), Box::new(Expr::Value(3)))),
+    );
+    assert_eq!(
+        format!("{:?}", expr.async_debug().await),
+        "Add(Value(1), Add(Value(2), Value(3)))",
+    );
+
+    let local = LocalNode {
+        value: Rc::new(1),
+        children: vec![LocalNode {
+            value: Rc::new(2),
+            children: vec![],
+        }],
+    };
+    assert_eq!(
+        format!("{:?}", local.async_debug().await),
+        "LocalNode { value: 1, children: [LocalNode { value: 2, children: [] }] }",
+    );
+}
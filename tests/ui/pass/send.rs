@@ -0,0 +1,62 @@
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use async_debug::AsyncDebug;
+use tokio::{sync::Mutex, task::LocalSet};
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(send)]
+struct Shared {
+    name: String,
+    #[async_debug(async_call = Mutex::lock, clone, ty = Vec<u64>)]
+    values: Mutex<Vec<u64>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(send, bound = "T: Send + Sync")]
+enum Either<T: std::fmt::Debug> {
+    Left(T),
+    Right {
+        #[async_debug(skip)]
+        _inner: Arc<T>,
+    },
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(local)]
+struct Local {
+    #[async_debug(clone, ty = Rc<RefCell<u8>>)]
+    value: Rc<RefCell<u8>>,
+}
+
+#[tokio::main]
+async fn main() {
+    let shared = Arc::new(Shared {
+        name: "test".into(),
+        values: Mutex::new(vec![1, 2]),
+    });
+
+    let rendered = tokio::spawn(async move { format!("{:?}", shared.async_debug().await) })
+        .await
+        .unwrap();
+    assert_eq!(rendered, "Shared { name: \"test\", values: [1, 2] }");
+
+    let either = Arc::new(Either::Left(42u8));
+    let rendered = tokio::spawn(async move { format!("{:?}", either.async_debug().await) })
+        .await
+        .unwrap();
+    assert_eq!(rendered, "Left(42)");
+
+    LocalSet::new()
+        .run_until(async {
+            let local = Rc::new(Local {
+                value: Rc::new(RefCell::new(7)),
+            });
+
+            let rendered =
+                tokio::task::spawn_local(async move { format!("{:?}", local.async_debug().await) })
+                    .await
+                    .unwrap();
+            assert_eq!(rendered, "Local { value: RefCell { value: 7 } }");
+        })
+        .await;
+}
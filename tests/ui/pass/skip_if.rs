@@ -0,0 +1,94 @@
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::RwLock;
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Queue {
+    name: String,
+    #[async_debug(skip_if = Option::is_none)]
+    parent: Option<String>,
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<u64>, skip_if_empty)]
+    pending: RwLock<Vec<u64>>,
+    #[async_debug(skip_if = is_zero)]
+    dropped: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+enum Task {
+    Waiting(#[async_debug(skip_if_empty)] String, u64),
+    Done {
+        #[async_debug(nested, skip_if_empty)]
+        queues: Vec<Queue>,
+    },
+}
+
+#[tokio::main]
+async fn main() {
+    let queue = Queue {
+        name: "queue".into(),
+        parent: None,
+        pending: RwLock::default(),
+        dropped: 0,
+    };
+
+    assert_eq!(
+        format!("{:?}", queue.async_debug().await),
+        "Queue { name: \"queue\" }"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            queue.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        "Queue { name: \"queue\" }"
+    );
+    assert!(queue.async_debug().await.parent.is_none());
+
+    let queue = Queue {
+        name: "queue".into(),
+        parent: Some("root".into()),
+        pending: RwLock::new(vec![1, 2]),
+        dropped: 3,
+    };
+    let expected = "Queue { name: \"queue\", parent: Some(\"root\"), pending: [1, 2], dropped: 3 }";
+
+    assert_eq!(format!("{:?}", queue.async_debug().await), expected);
+    assert_eq!(
+        format!(
+            "{:?}",
+            queue.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        expected
+    );
+    assert_eq!(
+        format!("{:#?}", queue.async_debug().await),
+        format!(
+            "{:#?}",
+            queue.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+    );
+
+    let waiting = Task::Waiting(String::new(), 1);
+    assert_eq!(format!("{:?}", waiting.async_debug().await), "Waiting(1)");
+
+    let done = Task::Done { queues: Vec::new() };
+    assert_eq!(format!("{:?}", done.async_debug().await), "Done");
+    assert_eq!(
+        format!(
+            "{:?}",
+            done.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        "Done"
+    );
+
+    let done = Task::Done {
+        queues: vec![queue],
+    };
+    assert_eq!(
+        format!("{:?}", done.async_debug().await),
+        format!("Done {{ queues: [{}] }}", expected),
+    );
+}
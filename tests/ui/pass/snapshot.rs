@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+
+use tokio::sync::{Mutex, RwLock};
+
+mod module {
+    use std::path::Path;
+
+    use async_debug::AsyncDebug;
+    use tokio::sync::{Mutex, RwLock};
+
+    #[derive(Debug, AsyncDebug)]
+    #[async_debug(snapshot = StructNamedSnapshot, snapshot_vis = pub(crate))]
+    pub(super) struct StructNamed<T>
+    where
+        T: AsRef<Path>,
+    {
+        pub string: String,
+        #[async_debug(async_call = RwLock::read, clone, ty = Vec<String>)]
+        pub rw_lock: RwLock<Vec<String>>,
+        pub path: T,
+    }
+
+    #[derive(Debug, AsyncDebug)]
+    #[async_debug(snapshot = EnumUnnamedSnapshot, module = enum_unnamed_snapshot)]
+    pub(super) enum EnumUnnamed {
+        Variant1(
+            String,
+            #[async_debug(async_call = Mutex::lock, copy, ty = u128)] Mutex<u128>,
+        ),
+    }
+}
+
+use async_debug::AsyncDebug;
+use module::{EnumUnnamedSnapshot, StructNamedSnapshot};
+
+struct Holder<'a> {
+    snapshot: StructNamedSnapshot<'a, PathBuf>,
+}
+
+fn render<T: AsyncDebug + ?Sized>(snapshot: &T::Snapshot<'_>) -> String
+where
+    for<'a> T::Snapshot<'a>: std::fmt::Debug,
+{
+    format!("{:?}", snapshot)
+}
+
+fn render_enum(snapshot: EnumUnnamedSnapshot<'_>) -> String {
+    format!("{:?}", snapshot)
+}
+
+fn render_struct<T: AsRef<Path> + std::fmt::Debug>(snapshot: &StructNamedSnapshot<'_, T>) -> String {
+    format!("{:?}", snapshot)
+}
+
+#[tokio::main]
+async fn main() {
+    let struct_named = module::StructNamed {
+        string: "test".into(),
+        rw_lock: RwLock::from(vec!["string0".into(), "string1".into()]),
+        path: PathBuf::from("/tmp"),
+    };
+
+    let holder = Holder {
+        snapshot: struct_named.async_debug().await,
+    };
+
+    assert_eq!(
+        render::<module::StructNamed<PathBuf>>(&holder.snapshot),
+        "StructNamed { string: \"test\", rw_lock: [\"string0\", \"string1\"], path: \"/tmp\" }",
+    );
+    assert_eq!(
+        render_struct(&holder.snapshot),
+        render::<module::StructNamed<PathBuf>>(&holder.snapshot),
+    );
+
+    let enum_unnamed = module::EnumUnnamed::Variant1("test".into(), Mutex::from(999));
+
+    let snapshot: module::enum_unnamed_snapshot::EnumUnnamed<_, _> =
+        enum_unnamed.async_debug().await;
+
+    assert_eq!(render_enum(snapshot), "Variant1(\"test\", 999)");
+}
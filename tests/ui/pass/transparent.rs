@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Debug, AsyncDebug)]
+struct State {
+    count: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(transparent)]
+struct Shared(#[async_debug(async_call = RwLock::read, clone, ty = State)] Arc<RwLock<State>>);
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(transparent)]
+struct Name {
+    #[async_debug(profile(brief, max_len = 2))]
+    name: String,
+    #[async_debug(skip)]
+    _id: u64,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Service {
+    #[async_debug(nested)]
+    shared: Shared,
+    #[async_debug(nested)]
+    name: Name,
+}
+
+#[tokio::main]
+async fn main() {
+    let service = Service {
+        shared: Shared(Arc::new(RwLock::new(State { count: 1 }))),
+        name: Name {
+            name: "service".into(),
+            _id: 2,
+        },
+    };
+
+    assert_eq!(
+        format!("{:?}", service.shared.async_debug().await),
+        "State { count: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", service.name.async_debug().await),
+        "\"service\""
+    );
+    assert_eq!(
+        format!("{:?}", service.async_debug().await),
+        "Service { shared: State { count: 1 }, name: \"service\" }"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            service.async_debug_with(&AsyncDebugOptions::new()).await
+        ),
+        "Service { shared: State { count: 1 }, name: \"service\" }"
+    );
+    assert_eq!(
+        format!("{:?}", service.name.async_debug_brief().await),
+        "\"se\" .. 5 more"
+    );
+}
@@ -0,0 +1,128 @@
+use std::fmt::{self, Display, Formatter};
+
+use async_debug::{AsyncDebug, AsyncDebugOptions, Rendered};
+use tokio::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Up,
+}
+
+#[derive(Debug, PartialEq)]
+struct Unreachable;
+
+#[derive(Debug, PartialEq)]
+enum RemoteError {
+    Unreachable,
+}
+
+impl From<Unreachable> for RemoteError {
+    fn from(_: Unreachable) -> Self {
+        Self::Unreachable
+    }
+}
+
+impl Display for RemoteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("unreachable")
+    }
+}
+
+struct Remote {
+    reachable: bool,
+}
+
+impl Remote {
+    async fn status(&self) -> Result<Status, Unreachable> {
+        if self.reachable {
+            Ok(Status::Up)
+        } else {
+            Err(Unreachable)
+        }
+    }
+
+    async fn peers(&self) -> Result<Vec<String>, Unreachable> {
+        if self.reachable {
+            Ok(vec!["a".into(), "b".into()])
+        } else {
+            Err(Unreachable)
+        }
+    }
+}
+
+#[derive(AsyncDebug)]
+#[async_debug(error = RemoteError)]
+struct Node {
+    #[async_debug(async_call = Remote::status, try, ty = Status)]
+    status: Remote,
+    #[async_debug(async_call = [Mutex::lock, Remote::peers], try, truncate, max_items = 1)]
+    peers: Mutex<Remote>,
+}
+
+#[derive(AsyncDebug)]
+#[async_debug(error = RemoteError)]
+enum Link {
+    Remote(#[async_debug(async_call = Remote::status, try, to_string)] Remote),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("up")
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let up = Node {
+        status: Remote { reachable: true },
+        peers: Mutex::new(Remote { reachable: true }),
+    };
+
+    let snapshot = up.async_debug().await;
+    assert_eq!(snapshot.status, Ok(Status::Up));
+    assert_eq!(
+        format!("{:?}", snapshot),
+        "Node { status: Ok(Up), peers: Ok([\"a\", .. 1 more]) }"
+    );
+    assert_eq!(
+        format!("{:?}", up.async_debug_with(&AsyncDebugOptions::new()).await),
+        "Node { status: Ok(Up), peers: Ok([\"a\", .. 1 more]) }"
+    );
+
+    let snapshot = up.try_async_debug().await.unwrap();
+    assert_eq!(snapshot.status, Status::Up);
+    assert_eq!(
+        format!("{:?}", snapshot),
+        "Node { status: Up, peers: [\"a\", .. 1 more] }"
+    );
+
+    let down = Node {
+        status: Remote { reachable: false },
+        peers: Mutex::new(Remote { reachable: true }),
+    };
+
+    let snapshot = down.async_debug().await;
+    assert_eq!(snapshot.status, Err(Rendered::new(&Unreachable)));
+    assert_eq!(
+        format!("{:?}", snapshot),
+        "Node { status: Err(Unreachable), peers: Ok([\"a\", .. 1 more]) }"
+    );
+    assert_eq!(
+        format!("{:?}", down.async_debug_with(&AsyncDebugOptions::new()).await),
+        "Node { status: Err(Unreachable), peers: Ok([\"a\", .. 1 more]) }"
+    );
+    assert_eq!(
+        down.try_async_debug().await.unwrap_err(),
+        RemoteError::Unreachable
+    );
+
+    let link = Link::Remote(Remote { reachable: true });
+    assert_eq!(
+        format!("{:?}", link.try_async_debug().await.unwrap()),
+        "Remote(\"up\")"
+    );
+    assert_eq!(
+        format!("{:?}", link.async_debug().await),
+        "Remote(Ok(\"up\"))"
+    );
+}
@@ -0,0 +1,83 @@
+use std::{
+    rc::{self, Rc},
+    sync::{Arc, Weak},
+};
+
+use async_debug::{AsyncDebug, AsyncDebugOptions, Upgraded};
+use tokio::sync::{Mutex, RwLock};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Parent {
+    name: String,
+}
+
+#[derive(AsyncDebug)]
+struct Sibling {
+    #[async_debug(async_call = Mutex::lock, copy, ty = u64)]
+    id: Mutex<u64>,
+}
+
+#[derive(AsyncDebug)]
+struct Child {
+    #[async_debug(upgrade, async_call = RwLock::read, clone, ty = Parent)]
+    parent: Weak<RwLock<Parent>>,
+    #[async_debug(upgrade, async_call = RwLock::read, clone, ty = Parent)]
+    orphaned: Weak<RwLock<Parent>>,
+    #[async_debug(optional, upgrade, nested)]
+    sibling: Option<rc::Weak<Sibling>>,
+    #[async_debug(upgrade, to_string)]
+    label: rc::Weak<str>,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let parent = Arc::new(RwLock::new(Parent {
+        name: "root".into(),
+    }));
+    let sibling = Rc::new(Sibling { id: Mutex::new(2) });
+    let label: Rc<str> = Rc::from("leaf");
+
+    let child = Child {
+        parent: Arc::downgrade(&parent),
+        orphaned: Arc::downgrade(&Arc::new(RwLock::new(Parent {
+            name: "gone".into(),
+        }))),
+        sibling: Some(Rc::downgrade(&sibling)),
+        label: Rc::downgrade(&label),
+    };
+
+    let snapshot = child.async_debug().await;
+    assert_eq!(
+        snapshot.parent,
+        Upgraded::Alive(Parent {
+            name: "root".into()
+        })
+    );
+    assert_eq!(snapshot.orphaned, Upgraded::Dropped);
+    assert_eq!(snapshot.label.alive().map(String::as_str), Some("leaf"));
+
+    let expected = "Child { \
+        parent: Parent { name: \"root\" }, \
+        orphaned: <dropped>, \
+        sibling: Some(Sibling { id: 2 }), \
+        label: \"leaf\" \
+    }";
+
+    assert_eq!(format!("{:?}", snapshot), expected);
+    assert_eq!(
+        format!("{:?}", child.async_debug_with(&AsyncDebugOptions::new()).await),
+        expected
+    );
+
+    drop((parent, sibling, label));
+
+    assert_eq!(
+        format!("{:?}", child.async_debug().await),
+        "Child { \
+            parent: <dropped>, \
+            orphaned: <dropped>, \
+            sibling: Some(<dropped>), \
+            label: <dropped> \
+        }"
+    );
+}
@@ -0,0 +1,63 @@
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+
+struct Secret;
+
+#[derive(AsyncDebug)]
+enum Event {
+    #[async_debug(rename = "Begin")]
+    Start { id: u64 },
+    #[async_debug(skip)]
+    Auth(Secret),
+    #[async_debug(skip)]
+    Login { token: Secret },
+    #[async_debug(name_only)]
+    Tick(u64),
+    #[async_debug(transparent)]
+    Message(String),
+    #[async_debug(transparent)]
+    Stop {
+        reason: String,
+        #[async_debug(skip)]
+        _code: u64,
+    },
+}
+
+#[derive(AsyncDebug)]
+struct Log {
+    #[async_debug(nested)]
+    events: Vec<Event>,
+}
+
+#[tokio::main]
+async fn main() {
+    let log = Log {
+        events: vec![
+            Event::Start { id: 1 },
+            Event::Auth(Secret),
+            Event::Login { token: Secret },
+            Event::Tick(2),
+            Event::Message("hello".into()),
+            Event::Stop {
+                reason: "done".into(),
+                _code: 3,
+            },
+        ],
+    };
+
+    let expected = "[Begin { id: 1 }, Auth(..), Login { .. }, Tick, \"hello\", \"done\"]";
+
+    let mut events = Vec::new();
+    for event in &log.events {
+        events.push(format!("{:?}", event.async_debug().await));
+    }
+    assert_eq!(format!("[{}]", events.join(", ")), expected);
+
+    assert_eq!(
+        format!("{:?}", log.async_debug().await),
+        format!("Log {{ events: {} }}", expected)
+    );
+    assert_eq!(
+        format!("{:?}", log.async_debug_with(&AsyncDebugOptions::new()).await),
+        format!("Log {{ events: {} }}", expected)
+    );
+}
@@ -0,0 +1,207 @@
+#![deny(non_camel_case_types)]
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Weak},
+};
+
+use async_debug::{AsyncDebug, AsyncDebugOptions};
+use tokio::sync::{Mutex, RwLock};
+
+#[derive(Debug, AsyncDebug)]
+struct StructNamed {
+    string: String,
+    #[async_debug(skip)]
+    integer: u64,
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<String>, attr(serde(rename = "lock")))]
+    rw_lock: RwLock<Vec<String>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+#[async_debug(transparent)]
+struct Transparent {
+    #[async_debug(async_call = RwLock::read, clone, ty = Vec<String>)]
+    rw_lock: RwLock<Vec<String>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Flatten {
+    id: u64,
+    #[async_debug(flatten)]
+    struct_named: StructNamed,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Pipeline {
+    #[async_debug(async_call = [RwLock::read, each(Mutex::lock)], copy, ty = u64)]
+    counts: RwLock<BTreeMap<u8, Mutex<u64>>>,
+    #[async_debug(async_call = each(Mutex::lock), copy, ty = u64)]
+    totals: Vec<Mutex<u64>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Upgrade {
+    #[async_debug(upgrade, async_call = Mutex::lock, copy, ty = u64)]
+    alive: Weak<Mutex<u64>>,
+    #[async_debug(upgrade, async_call = Mutex::lock, copy, ty = u64)]
+    dropped: Weak<Mutex<u64>>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Credentials {
+    user: String,
+    password: String,
+    expires: Option<u64>,
+}
+
+#[derive(Debug, AsyncDebug)]
+struct Peer(u8, String);
+
+#[derive(Debug, AsyncDebug)]
+struct Service {
+    name: String,
+    #[async_debug(async_call = Mutex::lock, nested)]
+    credentials: Mutex<Credentials>,
+    #[async_debug(nested)]
+    peers: Vec<Peer>,
+    #[async_debug(async_call = RwLock::read, truncate, max_items = 2)]
+    history: RwLock<Vec<u64>>,
+    #[async_debug(truncate, max_len = 4)]
+    description: String,
+}
+
+#[derive(Debug, AsyncDebug)]
+enum EnumUnnamed {
+    Variant1(
+        String,
+        #[async_debug(async_call = Mutex::lock, copy, ty = u128)] Mutex<u128>,
+    ),
+}
+
+#[derive(Debug, AsyncDebug)]
+enum EnumVariants {
+    #[async_debug(rename = "Paused")]
+    Pause { since: u64 },
+    #[async_debug(skip)]
+    Secret(String),
+    #[async_debug(skip)]
+    Hidden { token: String },
+    #[async_debug(name_only, rename = "Gone")]
+    Closed { reason: String },
+}
+
+#[tokio::main]
+async fn main() {
+    let struct_named = StructNamed {
+        string: "test".into(),
+        integer: 42,
+        rw_lock: RwLock::from(vec!["string0".into(), "string1".into()]),
+    };
+
+    assert_eq!(
+        serde_json::to_string(&struct_named.async_debug().await).unwrap(),
+        "{\"string\":\"test\",\"lock\":[\"string0\",\"string1\"]}",
+    );
+
+    let flatten = Flatten {
+        id: 1,
+        struct_named,
+    };
+
+    assert_eq!(
+        serde_json::to_string(&flatten.async_debug().await).unwrap(),
+        "{\"id\":1,\"string\":\"test\",\"lock\":[\"string0\",\"string1\"]}",
+    );
+
+    let transparent = Transparent {
+        rw_lock: RwLock::from(vec!["string0".into()]),
+    };
+
+    assert_eq!(
+        serde_json::to_string(&transparent.async_debug().await).unwrap(),
+        "[\"string0\"]",
+    );
+
+    let pipeline = Pipeline {
+        counts: RwLock::from(BTreeMap::from([(1, Mutex::from(2))])),
+        totals: vec![Mutex::from(3), Mutex::from(4)],
+    };
+
+    assert_eq!(
+        serde_json::to_string(&pipeline.async_debug().await).unwrap(),
+        "{\"counts\":{\"1\":2},\"totals\":[3,4]}",
+    );
+
+    let alive = Arc::new(Mutex::from(5));
+    let upgrade = Upgrade {
+        alive: Arc::downgrade(&alive),
+        dropped: Weak::new(),
+    };
+
+    assert_eq!(
+        serde_json::to_string(&upgrade.async_debug().await).unwrap(),
+        "{\"alive\":5,\"dropped\":null}",
+    );
+
+    let service = Service {
+        name: "service".into(),
+        credentials: Mutex::new(Credentials {
+            user: "admin".into(),
+            password: "hunter2".into(),
+            expires: None,
+        }),
+        peers: vec![Peer(1, "one".into())],
+        history: RwLock::new((0..5).collect()),
+        description: "primary".into(),
+    };
+
+    assert_eq!(
+        serde_json::to_string(&service.async_debug().await).unwrap(),
+        "{\"name\":\"service\",\
+         \"credentials\":{\"user\":\"admin\",\"password\":\"hunter2\",\"expires\":null},\
+         \"peers\":[[1,\"one\"]],\"history\":[0,1],\"description\":\"prim .. 3 more\"}",
+    );
+
+    let options = AsyncDebugOptions::new()
+        .with_redacted("password")
+        .with_redacted("description");
+
+    assert_eq!(
+        serde_json::to_string(&service.async_debug_with(&options).await).unwrap(),
+        "{\"name\":\"service\",\
+         \"credentials\":{\"user\":\"admin\",\"password\":\"<redacted>\",\"expires\":null},\
+         \"peers\":[[1,\"one\"]],\"history\":[0,1],\"description\":\"<redacted>\"}",
+    );
+
+    let enum_unnamed = EnumUnnamed::Variant1("test".into(), Mutex::from(999));
+
+    assert_eq!(
+        serde_json::to_string(&enum_unnamed.async_debug().await).unwrap(),
+        "{\"Variant1\":[\"test\",999]}",
+    );
+
+    let variants = [
+        EnumVariants::Pause { since: 7 },
+        EnumVariants::Secret("secret".into()),
+        EnumVariants::Hidden {
+            token: "token".into(),
+        },
+        EnumVariants::Closed {
+            reason: "reason".into(),
+        },
+    ];
+    let mut snapshots = Vec::new();
+
+    for variant in &variants {
+        snapshots.push(variant.async_debug().await);
+    }
+
+    assert_eq!(
+        format!("{:?}", snapshots),
+        "[Paused { since: 7 }, Secret(..), Hidden { .. }, Gone]",
+    );
+    assert_eq!(
+        serde_json::to_string(&snapshots).unwrap(),
+        "[{\"Paused\":{\"since\":7}},{\"Secret\":[]},{\"Hidden\":{}},\"Gone\"]",
+    );
+}